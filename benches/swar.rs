@@ -0,0 +1,208 @@
+//! Benchmarks comparing the vector operations against naive scalar loops
+//! that produce the same results.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use eight_bytes::{checksum, delta, find, mask8x8, u8x8};
+use std::hint::black_box;
+
+const SIZES: [usize; 3] = [64, 4096, 65536];
+
+fn sample_bytes(len: usize) -> Vec<u8> {
+    let mut state: u32 = 0x9e3779b9;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        })
+        .collect()
+}
+
+fn vectors(bytes: &[u8]) -> Vec<u8x8> {
+    bytes
+        .chunks_exact(8)
+        .map(|c| u8x8::from_array(c.try_into().unwrap()))
+        .collect()
+}
+
+fn arithmetic(c: &mut Criterion) {
+    let a = vectors(&sample_bytes(4096));
+    let b = vectors(&sample_bytes(4096)[1..4089]);
+    let mut group = c.benchmark_group("arithmetic");
+    group.throughput(Throughput::Bytes(4096));
+
+    macro_rules! binary_op {
+        ($name:ident, $scalar:expr) => {
+            group.bench_function(concat!(stringify!($name), "/swar"), |bench| {
+                bench.iter(|| {
+                    let (a, b) = (black_box(&a), black_box(&b));
+                    a.iter()
+                        .zip(b)
+                        .fold(u8x8::ZEROES, |acc, (a, b)| acc ^ (*a).$name(*b))
+                })
+            });
+            group.bench_function(concat!(stringify!($name), "/scalar"), |bench| {
+                bench.iter(|| {
+                    let (a, b) = (black_box(&a), black_box(&b));
+                    a.iter().zip(b).fold([0_u8; 8], |mut acc, (a, b)| {
+                        let (a, b) = (a.to_array(), b.to_array());
+                        for i in 0..8 {
+                            acc[i] ^= $scalar(a[i], b[i]);
+                        }
+                        acc
+                    })
+                })
+            });
+        };
+    }
+    binary_op!(wrapping_add, u8::wrapping_add);
+    binary_op!(saturating_add, u8::saturating_add);
+    binary_op!(saturating_sub, u8::saturating_sub);
+    binary_op!(abs_difference, u8::abs_diff);
+    binary_op!(max, core::cmp::max);
+    binary_op!(mean, |a: u8, b: u8| ((a as u16 + b as u16) / 2) as u8);
+    binary_op!(wrapping_mul, u8::wrapping_mul);
+    binary_op!(saturating_mul, u8::saturating_mul);
+    group.finish();
+}
+
+fn scans(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scans");
+    for size in SIZES {
+        let mut haystack = vec![b'a'; size];
+        haystack[size - 1] = b'z';
+        let data = sample_bytes(size);
+        group.throughput(Throughput::Bytes(size as u64));
+
+        group.bench_with_input(
+            BenchmarkId::new("position/swar", size),
+            &haystack,
+            |b, h| b.iter(|| find::position(black_box(h), b'z')),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("position/scalar", size),
+            &haystack,
+            |b, h| b.iter(|| black_box(h).iter().position(|b| *b == b'z')),
+        );
+
+        group.bench_with_input(BenchmarkId::new("count_eq/swar", size), &data, |b, d| {
+            b.iter(|| {
+                let (start, middle, end) = u8x8::from_byte_slice(black_box(d));
+                let scalar = start.iter().chain(end).filter(|b| **b == b'a').count();
+                let vector: u32 = middle.iter().map(|v| v.count_eq(b'a')).sum();
+                scalar + vector as usize
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("count_eq/scalar", size), &data, |b, d| {
+            b.iter(|| black_box(d).iter().filter(|b| **b == b'a').count())
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("delta_decode/swar", size),
+            &data,
+            |b, d| {
+                let mut buf = d.clone();
+                b.iter(|| delta::decode(black_box(&mut buf)))
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("delta_decode/scalar", size),
+            &data,
+            |b, d| {
+                let mut buf = d.clone();
+                b.iter(|| {
+                    let mut prev = 0_u8;
+                    for b in black_box(&mut buf).iter_mut() {
+                        *b = b.wrapping_add(prev);
+                        prev = *b;
+                    }
+                })
+            },
+        );
+
+        group.bench_with_input(BenchmarkId::new("adler32/swar", size), &data, |b, d| {
+            b.iter(|| {
+                use checksum::Checksum;
+                let mut c = checksum::Adler32::new();
+                c.update(black_box(d));
+                c.finalize()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("adler32/scalar", size), &data, |b, d| {
+            b.iter(|| {
+                let (mut a, mut s) = (1_u32, 0_u32);
+                for chunk in black_box(d).chunks(5552) {
+                    for v in chunk {
+                        a += *v as u32;
+                        s += a;
+                    }
+                    a %= 65521;
+                    s %= 65521;
+                }
+                (s << 16) | a
+            })
+        });
+    }
+    group.finish();
+}
+
+fn mask_conversions(c: &mut Criterion) {
+    let masks: Vec<mask8x8> = (0..=255).map(mask8x8::from_bitmask_le).collect();
+    let arrays: Vec<[bool; 8]> = masks.iter().map(|m| m.to_array()).collect();
+    let mut group = c.benchmark_group("mask_conversions");
+
+    group.bench_function("to_bitmask_le/swar", |b| {
+        b.iter(|| {
+            black_box(&masks)
+                .iter()
+                .fold(0_u8, |acc, m| acc ^ m.to_bitmask_le())
+        })
+    });
+    group.bench_function("to_bitmask_le/scalar", |b| {
+        b.iter(|| {
+            black_box(&arrays).iter().fold(0_u8, |acc, a| {
+                acc ^ (0..8).fold(0_u8, |bits, i| bits | ((a[i] as u8) << i))
+            })
+        })
+    });
+    group.bench_function("from_bitmask_le/swar", |b| {
+        b.iter(|| {
+            (0..=255_u8).fold(mask8x8::ALL_FALSE, |acc, bits| {
+                acc.or(mask8x8::from_bitmask_le(black_box(bits)))
+            })
+        })
+    });
+    group.bench_function("from_bitmask_le/scalar", |b| {
+        b.iter(|| {
+            (0..=255_u8).fold([false; 8], |mut acc, bits| {
+                let bits = black_box(bits);
+                for (i, v) in acc.iter_mut().enumerate() {
+                    *v |= bits & (1 << i) != 0;
+                }
+                acc
+            })
+        })
+    });
+    group.bench_function("select/swar", |b| {
+        b.iter(|| {
+            black_box(&masks)
+                .iter()
+                .fold(u8x8::ZEROES, |acc, m| acc ^ m.select(0xff, 0x01))
+        })
+    });
+    group.bench_function("select/scalar", |b| {
+        b.iter(|| {
+            black_box(&arrays).iter().fold([0_u8; 8], |mut acc, a| {
+                for i in 0..8 {
+                    acc[i] ^= if a[i] { 0xff } else { 0x01 };
+                }
+                acc
+            })
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, arithmetic, scans, mask_conversions);
+criterion_main!(benches);
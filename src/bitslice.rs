@@ -0,0 +1,80 @@
+//! Conversions between byte-oriented and bitsliced representations of
+//! 64 bytes of data.
+//!
+//! In the bitsliced representation, each of eight "bit planes" collects
+//! together the bits at one particular bit position across all 64 input
+//! bytes. A bitwise operation on a plane then acts on that bit position of
+//! all 64 bytes at once, which is the basis of constant-time bitsliced
+//! implementations of small ciphers and S-boxes: the S-box is expressed as a
+//! boolean circuit over the planes instead of as a table lookup.
+//!
+//! ```rust
+//! # use eight_bytes::{u8x8, bitslice};
+//! let mut bytes = [u8x8::ZEROES; 8];
+//! bytes[2] = u8x8::from_array([0, 0, 0, 0, 0, 0b0000_0100, 0, 0]);
+//! let planes = bitslice::to_planes(bytes);
+//! // Bit 2 of element 5 of vector 2 is in plane 2, element 2, bit 5.
+//! assert_eq!(planes[2].to_array(), [0, 0, 0b0010_0000, 0, 0, 0, 0, 0]);
+//! assert_eq!(bitslice::from_planes(planes), bytes);
+//! ```
+
+use crate::u8x8;
+
+/// Converts eight vectors of bytes into eight bit planes.
+///
+/// Bit `j` of element `i` of plane `b` in the result is bit `b` of element
+/// `j` of vector `i` in `v`, with bit zero being the least significant bit.
+/// Plane zero therefore collects all of the least significant bits.
+///
+/// This is the inverse of [`from_planes`].
+#[inline]
+pub const fn to_planes(v: [u8x8; 8]) -> [u8x8; 8] {
+    let mut t = [u8x8::ZEROES; 8];
+    let mut i = 0;
+    while i < 8 {
+        t[i] = v[i].transpose_bits();
+        i += 1;
+    }
+    transpose_elements(t)
+}
+
+/// Converts eight bit planes back into eight vectors of bytes.
+///
+/// Bit `b` of element `j` of vector `i` in the result is bit `j` of element
+/// `i` of plane `b` in `planes`.
+///
+/// This is the inverse of [`to_planes`].
+#[inline]
+pub const fn from_planes(planes: [u8x8; 8]) -> [u8x8; 8] {
+    let mut t = transpose_elements(planes);
+    let mut i = 0;
+    while i < 8 {
+        t[i] = t[i].transpose_bits();
+        i += 1;
+    }
+    t
+}
+
+/// Treats the given vectors as an 8x8 matrix of bytes where each vector is a
+/// row, and returns its transpose.
+#[inline(always)]
+pub(crate) const fn transpose_elements(v: [u8x8; 8]) -> [u8x8; 8] {
+    let mut rows = [[0_u8; 8]; 8];
+    let mut i = 0;
+    while i < 8 {
+        let row = v[i].to_array();
+        let mut j = 0;
+        while j < 8 {
+            rows[j][i] = row[j];
+            j += 1;
+        }
+        i += 1;
+    }
+    let mut ret = [u8x8::ZEROES; 8];
+    let mut i = 0;
+    while i < 8 {
+        ret[i] = u8x8::from_array(rows[i]);
+        i += 1;
+    }
+    ret
+}
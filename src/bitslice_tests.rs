@@ -0,0 +1,38 @@
+use super::*;
+
+fn sample_bytes() -> [u8x8; 8] {
+    let mut ret = [u8x8::ZEROES; 8];
+    let mut next: u8 = 1;
+    for v in ret.iter_mut() {
+        let mut a = [0_u8; 8];
+        for b in a.iter_mut() {
+            *b = next;
+            next = next.wrapping_mul(37).wrapping_add(11);
+        }
+        *v = u8x8::from_array(a);
+    }
+    ret
+}
+
+#[test]
+pub fn to_planes() {
+    let bytes = sample_bytes();
+    let got = bitslice::to_planes(bytes);
+    for (b, plane) in got.iter().enumerate() {
+        for (i, elem) in plane.to_array().into_iter().enumerate() {
+            for (j, byte) in bytes[i].to_array().into_iter().enumerate() {
+                let want = (byte >> b) & 1;
+                let got = (elem >> j) & 1;
+                assert_eq!(got, want, "plane {b}, element {i}, bit {j}");
+            }
+        }
+    }
+}
+
+#[test]
+pub fn from_planes() {
+    let bytes = sample_bytes();
+    let planes = bitslice::to_planes(bytes);
+    let got = bitslice::from_planes(planes);
+    assert_eq!(got, bytes);
+}
@@ -0,0 +1,317 @@
+//! Incremental checksum algorithms sharing a common [`Checksum`] trait.
+//!
+//! Each checksum type accumulates state across any number of calls to
+//! [`Checksum::update`], so the result is the same regardless of how the
+//! input is divided into chunks. Protocol code can be generic over the
+//! integrity algorithm by accepting any `C: Checksum`:
+//!
+//! ```rust
+//! # use eight_bytes::checksum::{Adler32, Checksum, Crc32};
+//! fn frame_check<C: Checksum>(mut c: C, header: &[u8], body: &[u8]) -> C::Output {
+//!     c.update(header);
+//!     c.update(body);
+//!     c.finalize()
+//! }
+//! assert_eq!(frame_check(Adler32::new(), b"Wiki", b"pedia"), 0x11e60398);
+//! assert_eq!(frame_check(Crc32::new(), b"1234", b"56789"), 0xcbf43926);
+//! ```
+
+use crate::u8x8;
+
+/// An incremental checksum algorithm.
+pub trait Checksum {
+    /// The type of the final checksum value.
+    type Output;
+
+    /// Adds the given bytes to the data covered by the checksum.
+    fn update(&mut self, data: &[u8]);
+
+    /// Returns the checksum of all of the data given to [`Self::update`]
+    /// since the checksum was created or last reset.
+    ///
+    /// This does not modify the state, so more data can be added afterwards
+    /// to find the checksum of a longer input.
+    fn finalize(&self) -> Self::Output;
+
+    /// Returns the checksum to its initial state, as if no data had been
+    /// added.
+    fn reset(&mut self);
+}
+
+/// The Adler-32 checksum, as used by zlib.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Adler32 {
+    a: u32,
+    b: u32,
+}
+
+impl Adler32 {
+    const MODULUS: u32 = 65521;
+
+    /// Returns a new checksum state covering no data.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self { a: 1, b: 0 }
+    }
+}
+
+impl Default for Adler32 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Checksum for Adler32 {
+    type Output = u32;
+
+    fn update(&mut self, data: &[u8]) {
+        (self.a, self.b) = running_sums(self.a, self.b, data, Self::MODULUS);
+    }
+
+    #[inline(always)]
+    fn finalize(&self) -> u32 {
+        (self.b << 16) | self.a
+    }
+
+    #[inline(always)]
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// The Fletcher-16 checksum, computed over individual bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fletcher16 {
+    a: u32,
+    b: u32,
+}
+
+impl Fletcher16 {
+    const MODULUS: u32 = 255;
+
+    /// Returns a new checksum state covering no data.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self { a: 0, b: 0 }
+    }
+}
+
+impl Default for Fletcher16 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Checksum for Fletcher16 {
+    type Output = u16;
+
+    fn update(&mut self, data: &[u8]) {
+        (self.a, self.b) = running_sums(self.a, self.b, data, Self::MODULUS);
+    }
+
+    #[inline(always)]
+    fn finalize(&self) -> u16 {
+        ((self.b << 8) | self.a) as u16
+    }
+
+    #[inline(always)]
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// The CRC-32 checksum with the polynomial used by Ethernet, zlib, and PNG.
+///
+/// Unlike the other checksums in this module this one is computed one byte
+/// at a time using a lookup table, because the polynomial division does not
+/// decompose into independent per-byte operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Crc32 {
+    crc: u32,
+}
+
+impl Crc32 {
+    const TABLE: [u32; 256] = {
+        let mut table = [0_u32; 256];
+        let mut i = 0;
+        while i < 256 {
+            let mut crc = i as u32;
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc & 1 != 0 {
+                    (crc >> 1) ^ 0xedb88320
+                } else {
+                    crc >> 1
+                };
+                bit += 1;
+            }
+            table[i] = crc;
+            i += 1;
+        }
+        table
+    };
+
+    /// Returns a new checksum state covering no data.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self { crc: !0 }
+    }
+}
+
+impl Default for Crc32 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Checksum for Crc32 {
+    type Output = u32;
+
+    fn update(&mut self, data: &[u8]) {
+        let mut crc = self.crc;
+        for b in data {
+            crc = Self::TABLE[((crc ^ *b as u32) & 0xff) as usize] ^ (crc >> 8);
+        }
+        self.crc = crc;
+    }
+
+    #[inline(always)]
+    fn finalize(&self) -> u32 {
+        !self.crc
+    }
+
+    #[inline(always)]
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// The 16-bit ones' complement checksum used by IPv4, TCP, and UDP, as
+/// described in RFC 1071.
+///
+/// The input is treated as a sequence of big-endian 16-bit words, with an
+/// odd trailing byte padded with zero. The result should be written into
+/// the protocol header in big-endian byte order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InternetChecksum {
+    sum: u64,
+    odd: bool,
+}
+
+impl InternetChecksum {
+    /// Returns a new checksum state covering no data.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self { sum: 0, odd: false }
+    }
+
+    #[inline(always)]
+    const fn add_byte(&mut self, b: u8) {
+        self.sum += if self.odd { b as u64 } else { (b as u64) << 8 };
+        self.odd = !self.odd;
+    }
+
+    #[inline(always)]
+    const fn fold(mut sum: u64) -> u64 {
+        while sum > 0xffff {
+            sum = (sum & 0xffff) + (sum >> 16);
+        }
+        sum
+    }
+}
+
+impl Default for InternetChecksum {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Checksum for InternetChecksum {
+    type Output = u16;
+
+    fn update(&mut self, data: &[u8]) {
+        let (start, middle, end) = u8x8::from_byte_slice(data);
+        for b in start {
+            self.add_byte(*b);
+        }
+        // Adding whole 32-bit words gives the same folded result as adding
+        // 16-bit words, so each vector contributes two additions.
+        let mut sum = 0_u64;
+        for v in middle {
+            let n = u64::from_be_bytes(v.to_array());
+            sum = Self::fold(sum) + (n >> 32) + (n & 0xffffffff);
+        }
+        let sum = Self::fold(sum);
+        // If the vectors began at an odd offset then each of their words
+        // straddles two of ours, which is equivalent to swapping the bytes
+        // of the folded sum.
+        self.sum += if self.odd {
+            (sum as u16).swap_bytes() as u64
+        } else {
+            sum
+        };
+        for b in end {
+            self.add_byte(*b);
+        }
+        self.sum = Self::fold(self.sum);
+    }
+
+    #[inline(always)]
+    fn finalize(&self) -> u16 {
+        !(Self::fold(self.sum) as u16)
+    }
+
+    #[inline(always)]
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// Updates the two running sums shared by the Adler and Fletcher family
+/// of checksums, where `a` is the sum of all bytes and `b` is the sum of
+/// all intermediate values of `a`, both modulo `modulus`.
+fn running_sums(mut a: u32, mut b: u32, data: &[u8], modulus: u32) -> (u32, u32) {
+    // The largest number of bytes we can accumulate before reducing by the
+    // modulus without risk of overflowing `b`, for moduli up to 65521.
+    const CHUNK_LEN: usize = 5552;
+
+    for chunk in data.chunks(CHUNK_LEN) {
+        let (start, middle, end) = u8x8::from_byte_slice(chunk);
+        for v in start {
+            a += *v as u32;
+            b += a;
+        }
+        for v in middle {
+            b += 8 * a + weighted_sum(*v);
+            a += v.reduce_sum() as u32;
+        }
+        for v in end {
+            a += *v as u32;
+            b += a;
+        }
+        a %= modulus;
+        b %= modulus;
+    }
+    (a, b)
+}
+
+/// Returns the sum of each element multiplied by eight minus its index,
+/// which is how much the elements of one vector contribute to the `b`
+/// sum of [`running_sums`] on top of the incoming `a` value.
+#[inline(always)]
+fn weighted_sum(v: u8x8) -> u32 {
+    const LOW_BYTES: u64 = 0x00ff00ff00ff00ff;
+    // Multiplying 16-bit lanes by a vector of 16-bit weights in reverse
+    // order accumulates their dot product in the most significant lane.
+    // The products are small enough that no lane can carry into the next.
+    const EVEN_WEIGHTS: u64 = 0x0008000600040002;
+    const ODD_WEIGHTS: u64 = 0x0007000500030001;
+    let n = v.n.to_le();
+    let even = (n & LOW_BYTES).wrapping_mul(EVEN_WEIGHTS) >> 48;
+    let odd = ((n >> 8) & LOW_BYTES).wrapping_mul(ODD_WEIGHTS) >> 48;
+    (even + odd) as u32
+}
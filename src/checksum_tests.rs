@@ -0,0 +1,112 @@
+use super::*;
+use checksum::Checksum;
+
+fn sample_bytes() -> [u8; 200] {
+    let mut ret = [0_u8; 200];
+    let mut next: u8 = 0xe1;
+    for b in ret.iter_mut() {
+        *b = next;
+        next = next.wrapping_mul(29).wrapping_add(3);
+    }
+    ret
+}
+
+/// Asserts that the checksum is the same regardless of how the input is
+/// split between calls to `update`, including at unaligned offsets.
+fn assert_chunking_invariant<C: Checksum + Default>(data: &[u8])
+where
+    C::Output: PartialEq + core::fmt::Debug,
+{
+    let mut whole = C::default();
+    whole.update(data);
+    let want = whole.finalize();
+    for split in [1, 3, 7, 8, 13, 64, 101] {
+        let mut c = C::default();
+        for chunk in data.chunks(split) {
+            c.update(chunk);
+        }
+        assert_eq!(c.finalize(), want, "chunks of {split}");
+    }
+}
+
+#[test]
+pub fn adler32() {
+    let mut c = checksum::Adler32::new();
+    c.update(b"Wikipedia");
+    assert_eq!(c.finalize(), 0x11e60398);
+
+    let data = sample_bytes();
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for v in data {
+        a = (a + v as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    c.reset();
+    c.update(&data);
+    assert_eq!(c.finalize(), (b << 16) | a);
+    assert_chunking_invariant::<checksum::Adler32>(&data);
+}
+
+#[test]
+pub fn adler32_long() {
+    let data = [0xff_u8; 20000];
+    let mut c = checksum::Adler32::new();
+    c.update(&data);
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for v in data {
+        a = (a + v as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    assert_eq!(c.finalize(), (b << 16) | a);
+}
+
+#[test]
+pub fn fletcher16() {
+    let mut c = checksum::Fletcher16::new();
+    c.update(b"abcde");
+    assert_eq!(c.finalize(), 0xc8f0);
+    c.update(b"f");
+    assert_eq!(c.finalize(), 0x2057);
+    c.reset();
+    c.update(b"abcdefgh");
+    assert_eq!(c.finalize(), 0x0627);
+    assert_chunking_invariant::<checksum::Fletcher16>(&sample_bytes());
+}
+
+#[test]
+pub fn crc32() {
+    let mut c = checksum::Crc32::new();
+    c.update(b"123456789");
+    assert_eq!(c.finalize(), 0xcbf43926);
+    c.reset();
+    assert_eq!(c.finalize(), 0);
+    assert_chunking_invariant::<checksum::Crc32>(&sample_bytes());
+}
+
+#[test]
+pub fn internet_checksum() {
+    // This is the example from RFC 1071 section 3.
+    let mut c = checksum::InternetChecksum::new();
+    c.update(&[0x00, 0x01, 0xf2, 0x03, 0xf4, 0xf5, 0xf6, 0xf7]);
+    assert_eq!(c.finalize(), !0xddf2);
+
+    let data = sample_bytes();
+    for offset in 0..8 {
+        // Starting at different offsets changes how many bytes are handled
+        // before the first aligned vector, so we test both parities.
+        let data = &data[offset..];
+        let mut sum = 0_u32;
+        for pair in data.chunks(2) {
+            let hi = pair[0] as u32;
+            let lo = pair.get(1).copied().unwrap_or(0) as u32;
+            sum += (hi << 8) | lo;
+        }
+        while sum > 0xffff {
+            sum = (sum & 0xffff) + (sum >> 16);
+        }
+        c.reset();
+        c.update(data);
+        assert_eq!(c.finalize(), !(sum as u16), "offset {offset}");
+        assert_chunking_invariant::<checksum::InternetChecksum>(data);
+    }
+}
@@ -0,0 +1,59 @@
+//! Delta encoding and decoding of byte slices, in place.
+//!
+//! Delta encoding replaces each byte with its difference from the byte
+//! before it, modulo 256. Slowly-changing data such as sensor readings then
+//! becomes mostly small values, which general-purpose compressors handle
+//! much better than the original data.
+//!
+//! ```rust
+//! # use eight_bytes::delta;
+//! let mut buf = [10, 11, 13, 13, 12, 200, 5];
+//! delta::encode(&mut buf);
+//! assert_eq!(buf, [10, 1, 2, 0, 255, 188, 61]);
+//! delta::decode(&mut buf);
+//! assert_eq!(buf, [10, 11, 13, 13, 12, 200, 5]);
+//! ```
+
+use crate::u8x8;
+
+/// Replaces each byte in the slice with its difference from the byte before
+/// it, modulo 256. The first byte is treated as if it were preceded by zero,
+/// and so is left unchanged.
+///
+/// This is the inverse of [`decode`].
+pub fn encode(buf: &mut [u8]) {
+    let (start, middle, end) = u8x8::from_byte_slice_mut(buf);
+    let mut prev = 0_u8;
+    for b in start {
+        (*b, prev) = (b.wrapping_sub(prev), *b);
+    }
+    for v in middle {
+        let orig = *v;
+        *v = orig.wrapping_sub(orig.shift_elements_up(prev));
+        prev = orig.to_array()[7];
+    }
+    for b in end {
+        (*b, prev) = (b.wrapping_sub(prev), *b);
+    }
+}
+
+/// Replaces each byte in the slice with the sum of itself and all of the
+/// bytes before it, modulo 256.
+///
+/// This is the inverse of [`encode`].
+pub fn decode(buf: &mut [u8]) {
+    let (start, middle, end) = u8x8::from_byte_slice_mut(buf);
+    let mut prev = 0_u8;
+    for b in start {
+        *b = b.wrapping_add(prev);
+        prev = *b;
+    }
+    for v in middle {
+        *v = v.wrapping_prefix_sum().wrapping_add(u8x8::splat(prev));
+        prev = v.to_array()[7];
+    }
+    for b in end {
+        *b = b.wrapping_add(prev);
+        prev = *b;
+    }
+}
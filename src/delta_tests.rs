@@ -0,0 +1,40 @@
+use super::*;
+
+fn sample_bytes() -> [u8; 45] {
+    let mut ret = [0_u8; 45];
+    let mut next: u8 = 7;
+    for b in ret.iter_mut() {
+        *b = next;
+        next = next.wrapping_mul(13).wrapping_add(5);
+    }
+    ret
+}
+
+#[test]
+pub fn encode() {
+    let orig = sample_bytes();
+    // We test at all possible alignments so that the scalar start and end
+    // portions vary in length.
+    for offset in 0..8 {
+        let mut buf = orig;
+        let buf = &mut buf[offset..];
+        delta::encode(buf);
+        let mut prev = 0_u8;
+        for (i, (got, orig)) in buf.iter().zip(&orig[offset..]).enumerate() {
+            assert_eq!(*got, orig.wrapping_sub(prev), "offset {offset}, index {i}");
+            prev = *orig;
+        }
+    }
+}
+
+#[test]
+pub fn decode() {
+    let orig = sample_bytes();
+    for offset in 0..8 {
+        let mut buf = orig;
+        let buf = &mut buf[offset..];
+        delta::encode(buf);
+        delta::decode(buf);
+        assert_eq!(buf, &orig[offset..], "offset {offset}");
+    }
+}
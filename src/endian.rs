@@ -0,0 +1,60 @@
+//! In-place byte order conversion of arrays of wider integers stored as
+//! bytes.
+//!
+//! Each function reverses the byte order of every element in the slice,
+//! which converts between big-endian and little-endian representations in
+//! either direction. Eight bytes are processed at a time.
+//!
+//! ```rust
+//! # use eight_bytes::endian;
+//! let mut buf = [0x12, 0x34, 0xab, 0xcd, 0x00, 0x01];
+//! endian::swap_u16_slice(&mut buf);
+//! assert_eq!(buf, [0x34, 0x12, 0xcd, 0xab, 0x01, 0x00]);
+//! ```
+
+use crate::u8x8;
+
+/// Reverses the byte order of each two-byte element of `buf`.
+///
+/// # Panics
+///
+/// Panics if the length of `buf` is not a multiple of two.
+pub fn swap_u16_slice(buf: &mut [u8]) {
+    swap_slice::<2>(buf, u8x8::swap_bytes_u16);
+}
+
+/// Reverses the byte order of each four-byte element of `buf`.
+///
+/// # Panics
+///
+/// Panics if the length of `buf` is not a multiple of four.
+pub fn swap_u32_slice(buf: &mut [u8]) {
+    swap_slice::<4>(buf, u8x8::swap_bytes_u32);
+}
+
+/// Reverses the byte order of each eight-byte element of `buf`.
+///
+/// # Panics
+///
+/// Panics if the length of `buf` is not a multiple of eight.
+pub fn swap_u64_slice(buf: &mut [u8]) {
+    swap_slice::<8>(buf, |v| u8x8::new(v.n.swap_bytes()));
+}
+
+#[inline(always)]
+fn swap_slice<const N: usize>(buf: &mut [u8], swap: impl Fn(u8x8) -> u8x8) {
+    assert!(
+        buf.len().is_multiple_of(N),
+        "length must be a multiple of the element size"
+    );
+    // The vectors must start at element boundaries, so we can't use
+    // u8x8::from_byte_slice_mut here: the slice might not be aligned.
+    let mut chunks = buf.chunks_exact_mut(8);
+    for chunk in &mut chunks {
+        let v = u8x8::from_array((*chunk).try_into().unwrap());
+        chunk.copy_from_slice(&swap(v).to_array());
+    }
+    for elem in chunks.into_remainder().chunks_exact_mut(N) {
+        elem.reverse();
+    }
+}
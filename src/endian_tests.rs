@@ -0,0 +1,47 @@
+use super::*;
+
+fn sample_bytes() -> [u8; 40] {
+    core::array::from_fn(|i| i as u8)
+}
+
+#[test]
+pub fn swap_u16_slice() {
+    let mut got = sample_bytes();
+    endian::swap_u16_slice(&mut got[2..]);
+    let mut want = sample_bytes();
+    for pair in want[2..].chunks_exact_mut(2) {
+        let v = u16::from_le_bytes(pair.try_into().unwrap());
+        pair.copy_from_slice(&v.to_be_bytes());
+    }
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn swap_u32_slice() {
+    let mut got = sample_bytes();
+    endian::swap_u32_slice(&mut got[4..]);
+    let mut want = sample_bytes();
+    for quad in want[4..].chunks_exact_mut(4) {
+        let v = u32::from_le_bytes(quad.try_into().unwrap());
+        quad.copy_from_slice(&v.to_be_bytes());
+    }
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn swap_u64_slice() {
+    let mut got = sample_bytes();
+    endian::swap_u64_slice(&mut got[8..]);
+    let mut want = sample_bytes();
+    for oct in want[8..].chunks_exact_mut(8) {
+        let v = u64::from_le_bytes(oct.try_into().unwrap());
+        oct.copy_from_slice(&v.to_be_bytes());
+    }
+    assert_eq!(got, want);
+}
+
+#[test]
+#[should_panic]
+pub fn swap_u32_slice_wrong_length() {
+    endian::swap_u32_slice(&mut [0; 6]);
+}
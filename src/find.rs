@@ -0,0 +1,156 @@
+//! Searching byte slices for a particular byte value, or for short
+//! multi-byte patterns in data that arrives in chunks.
+//!
+//! ```rust
+//! # use eight_bytes::find;
+//! let haystack = b"one,two,,three";
+//! assert_eq!(find::position(haystack, b','), Some(3));
+//! let commas: Vec<usize> = find::positions(haystack, b',').collect();
+//! assert_eq!(commas, [3, 7, 8]);
+//! ```
+
+use crate::{mask8x8, u8x8};
+
+/// Returns the index of the first byte in `haystack` that equals `needle`,
+/// or `None` if there is no such byte.
+#[inline]
+pub fn position(haystack: &[u8], needle: u8) -> Option<usize> {
+    positions(haystack, needle).next()
+}
+
+/// Returns an iterator over the indices of all of the bytes in `haystack`
+/// that equal `needle`, in increasing order.
+#[inline]
+pub fn positions(haystack: &[u8], needle: u8) -> Positions<'_> {
+    Positions {
+        haystack,
+        needle: u8x8::splat(needle),
+        next_start: 0,
+        chunk_start: 0,
+        pending: 0,
+    }
+}
+
+/// Returns the indices of all of the bytes in `haystack` that equal
+/// `needle`, in increasing order, in a newly-allocated vector.
+#[cfg(feature = "alloc")]
+pub fn all_positions(haystack: &[u8], needle: u8) -> alloc::vec::Vec<usize> {
+    positions(haystack, needle).collect()
+}
+
+/// Iterator returned by [`positions`].
+#[derive(Clone, Debug)]
+pub struct Positions<'a> {
+    haystack: &'a [u8],
+    needle: u8x8,
+    next_start: usize,
+    chunk_start: usize,
+    /// Bitmask of the matches in the current chunk that have not yet been
+    /// returned, with the first element in the least significant bit.
+    pending: u8,
+}
+
+impl Iterator for Positions<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.pending == 0 {
+            let start = self.next_start;
+            let chunk = self.haystack.get(start..)?;
+            if chunk.is_empty() {
+                return None;
+            }
+            let len = core::cmp::min(chunk.len(), 8);
+            let mut a = [0_u8; 8];
+            a[..len].copy_from_slice(&chunk[..len]);
+            let matches = u8x8::from_array(a).equals(self.needle).to_bitmask_le();
+            // Padding elements beyond the end of the haystack must not match.
+            self.pending = matches & (0xff_u16 >> (8 - len)) as u8;
+            self.chunk_start = start;
+            self.next_start = start + 8;
+        }
+        let i = self.pending.trailing_zeros() as usize;
+        self.pending &= self.pending - 1;
+        Some(self.chunk_start + i)
+    }
+}
+
+/// Finds occurrences of a pattern of up to eight bytes in a stream of
+/// [`u8x8`] chunks, including occurrences that span the boundary between
+/// two consecutive chunks.
+///
+/// The scanner retains the previous chunk so that the leading bytes of a
+/// match can come from there while the final byte is in the current chunk.
+///
+/// ```rust
+/// # use eight_bytes::{find::WindowScanner, u8x8};
+/// let mut scanner = WindowScanner::new(b"\r\n");
+/// let a = scanner.push(u8x8::from_array(*b"GET / \r\n"));
+/// let b = scanner.push(u8x8::from_array(*b"Host: a\r"));
+/// let c = scanner.push(u8x8::from_array(*b"\n\r\nxxxxx"));
+/// assert_eq!(a.to_bitmask_le(), 0b1000_0000);
+/// assert_eq!(b.to_bitmask_le(), 0b0000_0000);
+/// assert_eq!(c.to_bitmask_le(), 0b0000_0101);
+/// ```
+#[derive(Clone, Debug)]
+pub struct WindowScanner {
+    /// Each byte of the pattern splatted across a vector, starting with the
+    /// final byte of the pattern.
+    pattern: [u8x8; 8],
+    len: usize,
+    prev: u8x8,
+    /// Whether `prev` holds a chunk pushed since creation or the last reset.
+    primed: bool,
+}
+
+impl WindowScanner {
+    /// Creates a scanner that finds occurrences of `pattern`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is empty or longer than eight bytes.
+    pub fn new(pattern: &[u8]) -> Self {
+        assert!(
+            (1..=8).contains(&pattern.len()),
+            "pattern must be between one and eight bytes long"
+        );
+        let mut splatted = [u8x8::ZEROES; 8];
+        for (dst, &b) in splatted.iter_mut().zip(pattern.iter().rev()) {
+            *dst = u8x8::splat(b);
+        }
+        Self {
+            pattern: splatted,
+            len: pattern.len(),
+            prev: u8x8::ZEROES,
+            primed: false,
+        }
+    }
+
+    /// Feeds the next chunk of the stream to the scanner, returning a mask
+    /// that is true for each element of `chunk` that is the final byte of an
+    /// occurrence of the pattern.
+    pub fn push(&mut self, chunk: u8x8) -> mask8x8 {
+        let cur = chunk.n.to_le();
+        let prev = self.prev.n.to_le();
+        let mut found = chunk.equals(self.pattern[0]);
+        for k in 1..self.len {
+            let shift = 8 * k as u32;
+            let window = u8x8::new(u64::from_le((cur << shift) | (prev >> (64 - shift))));
+            found = found.and(window.equals(self.pattern[k]));
+        }
+        // Before we've seen enough bytes, matches ending early in the chunk
+        // would be relying on the zeroed initial value of `prev`.
+        let missing = if self.primed { 0 } else { self.len - 1 };
+        found = found.and(mask8x8::from_bitmask_le(0xff << missing));
+        self.prev = chunk;
+        self.primed = true;
+        found
+    }
+
+    /// Forgets the previously-pushed chunk, so that the next chunk is
+    /// treated as the start of a new stream.
+    pub fn reset(&mut self) {
+        self.prev = u8x8::ZEROES;
+        self.primed = false;
+    }
+}
@@ -0,0 +1,67 @@
+use super::*;
+
+#[test]
+pub fn position() {
+    let haystack = b"the quick brown fox jumps over the lazy dog";
+    assert_eq!(find::position(haystack, b'q'), Some(4));
+    assert_eq!(find::position(haystack, b'g'), Some(42));
+    assert_eq!(find::position(haystack, b'!'), None);
+    assert_eq!(find::position(b"", b'!'), None);
+}
+
+#[test]
+pub fn positions() {
+    let haystack = b"the quick brown fox jumps over the lazy dog";
+    let got: Vec<usize> = find::positions(haystack, b'o').collect();
+    assert_eq!(got, [12, 17, 26, 41]);
+    // The zero-filled padding after the final short chunk must not match.
+    let got: Vec<usize> = find::positions(b"\0a\0", 0).collect();
+    assert_eq!(got, [0, 2]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+pub fn all_positions() {
+    let got = find::all_positions(b"a,b,,c", b',');
+    assert_eq!(got, [1, 3, 4]);
+}
+
+#[test]
+pub fn window_scanner() {
+    let haystack = b"abcabcab\0\0cab\0abcabcabcabcabcabc";
+    for pattern in [&b"abc"[..], b"a", b"cabcabca", b"\0\0", b"\0abcabca"] {
+        let mut scanner = find::WindowScanner::new(pattern);
+        let mut got = Vec::new();
+        for (i, chunk) in haystack.chunks_exact(8).enumerate() {
+            let chunk = u8x8::from_array(chunk.try_into().unwrap());
+            let mut found = scanner.push(chunk).to_bitmask_le();
+            while found != 0 {
+                got.push(i * 8 + found.trailing_zeros() as usize);
+                found &= found - 1;
+            }
+        }
+        let want: Vec<usize> = haystack
+            .windows(pattern.len())
+            .enumerate()
+            .filter(|(_, w)| *w == pattern)
+            .map(|(i, _)| i + pattern.len() - 1)
+            .collect();
+        assert_eq!(got, want, "{pattern:?}");
+    }
+
+    // The zeroed initial state must not produce matches, even after a reset.
+    let mut scanner = find::WindowScanner::new(b"\0\0\0x");
+    assert_eq!(
+        scanner
+            .push(u8x8::from_array(*b"x\0\0\0xabc"))
+            .to_bitmask_le(),
+        0b1_0000
+    );
+    scanner.reset();
+    assert_eq!(
+        scanner
+            .push(u8x8::from_array(*b"\0x\0\0\0x\0\0"))
+            .to_bitmask_le(),
+        0b10_0000
+    );
+}
@@ -0,0 +1,79 @@
+//! Encoding of byte slices as lowercase hexadecimal text.
+//!
+//! ```rust
+//! # use eight_bytes::hex;
+//! let mut buf = [0_u8; 8];
+//! hex::encode(&[0xde, 0xad, 0xbe, 0xef], &mut buf);
+//! assert_eq!(&buf, b"deadbeef");
+//! ```
+
+use crate::u8x8;
+
+/// Returns the number of bytes that [`encode`] writes when encoding a
+/// source slice of the given length.
+#[inline(always)]
+pub const fn encoded_len(src_len: usize) -> usize {
+    src_len * 2
+}
+
+/// Writes the lowercase hexadecimal representation of `src` into the first
+/// [`encoded_len`] bytes of `dst`, two digits per byte of `src` with the
+/// most significant digit first.
+///
+/// # Panics
+///
+/// Panics if `dst` is shorter than [`encoded_len`] bytes.
+pub fn encode(src: &[u8], dst: &mut [u8]) {
+    let dst = &mut dst[..encoded_len(src.len())];
+    let mut src_chunks = src.chunks_exact(8);
+    let mut dst_chunks = dst.chunks_exact_mut(16);
+    for (src, dst) in (&mut src_chunks).zip(&mut dst_chunks) {
+        let (hi, lo) = hex_digits(u8x8::from_array(src.try_into().unwrap()));
+        let (hi, lo) = (hi.to_array(), lo.to_array());
+        for i in 0..8 {
+            dst[i * 2] = hi[i];
+            dst[i * 2 + 1] = lo[i];
+        }
+    }
+    let src = src_chunks.remainder();
+    if !src.is_empty() {
+        let mut a = [0_u8; 8];
+        a[..src.len()].copy_from_slice(src);
+        let (hi, lo) = hex_digits(u8x8::from_array(a));
+        let (hi, lo) = (hi.to_array(), lo.to_array());
+        let dst = dst_chunks.into_remainder();
+        for i in 0..src.len() {
+            dst[i * 2] = hi[i];
+            dst[i * 2 + 1] = lo[i];
+        }
+    }
+}
+
+/// Returns the lowercase hexadecimal representation of `src` in a
+/// newly-allocated vector.
+#[cfg(feature = "alloc")]
+pub fn encode_to_vec(src: &[u8]) -> alloc::vec::Vec<u8> {
+    let mut ret = alloc::vec![0_u8; encoded_len(src.len())];
+    encode(src, &mut ret);
+    ret
+}
+
+/// Returns the lowercase ASCII hex digits for the high and low nibbles of
+/// each element, respectively.
+#[inline(always)]
+pub(crate) const fn hex_digits(v: u8x8) -> (u8x8, u8x8) {
+    const LOW_NIBBLES: u64 = 0x0f0f0f0f0f0f0f0f;
+    let hi = u8x8::new((v.n >> 4) & LOW_NIBBLES);
+    let lo = u8x8::new(v.n & LOW_NIBBLES);
+    (nibble_to_ascii(hi), nibble_to_ascii(lo))
+}
+
+#[inline(always)]
+const fn nibble_to_ascii(v: u8x8) -> u8x8 {
+    // Letters don't immediately follow digits in ASCII, so values greater
+    // than nine need to skip over the gap.
+    let letters = v
+        .greater_than(u8x8::splat(9))
+        .to_u8x8_with(b'a' - b'0' - 10);
+    v.wrapping_add(u8x8::splat(b'0')).wrapping_add(letters)
+}
@@ -0,0 +1,17 @@
+use super::*;
+
+#[test]
+pub fn encode() {
+    let src: [u8; 19] = core::array::from_fn(|i| (i as u8).wrapping_mul(29));
+    let mut got = [b'!'; 40];
+    hex::encode(&src, &mut got);
+    // The final two bytes are beyond the encoded length, so are untouched.
+    assert_eq!(&got, b"001d3a577491aecbe805223f5c7996b3d0ed0a!!");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+pub fn encode_to_vec() {
+    let got = hex::encode_to_vec(&[0x01, 0xab, 0xff]);
+    assert_eq!(got, b"01abff");
+}
@@ -0,0 +1,78 @@
+//! Hex dump formatting of byte slices.
+//!
+//! [`HexDump`] renders its bytes in the same layout as the `hexdump -C`
+//! command: sixteen bytes per line, with the offset of the first byte on
+//! the left and the printable ASCII characters in a gutter on the right.
+//! Because it implements [`core::fmt::Display`], it can be written to any
+//! formatting sink, including on `no_std` targets.
+//!
+//! ```rust
+//! # use eight_bytes::hexdump::HexDump;
+//! let dump = format!("{}", HexDump(b"Hello, world!\n"));
+//! assert_eq!(
+//!     dump,
+//!     "00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a        |Hello, world!.|\n",
+//! );
+//! ```
+
+use crate::{hex::hex_digits, u8x8};
+
+/// Formats the wrapped byte slice as a multi-line hex dump.
+///
+/// Each line, including the last, ends with a newline. An empty slice
+/// produces no output at all.
+#[derive(Clone, Copy)]
+pub struct HexDump<'a>(pub &'a [u8]);
+
+impl core::fmt::Display for HexDump<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Each line has sixteen three-character hex bytes with an extra
+        // space in the middle and another before the gutter.
+        const HEX_LEN: usize = 16 * 3 + 2;
+        let mut line = [b' '; HEX_LEN + 16 + 3];
+
+        for (i, chunk) in self.0.chunks(16).enumerate() {
+            write!(f, "{:08x}  ", i * 16)?;
+            line.fill(b' ');
+            for (half, chunk) in chunk.chunks(8).enumerate() {
+                let mut a = [0_u8; 8];
+                a[..chunk.len()].copy_from_slice(chunk);
+                let v = u8x8::from_array(a);
+                let (hi, lo) = hex_digits(v);
+                let (hi, lo) = (hi.to_array(), lo.to_array());
+                let base = half * 25;
+                for j in 0..chunk.len() {
+                    line[base + j * 3] = hi[j];
+                    line[base + j * 3 + 1] = lo[j];
+                }
+                let gutter = HEX_LEN + 1 + half * 8;
+                line[gutter..gutter + chunk.len()]
+                    .copy_from_slice(&printable(v).to_array()[..chunk.len()]);
+            }
+            line[HEX_LEN] = b'|';
+            let end = HEX_LEN + 1 + chunk.len();
+            line[end] = b'|';
+            line[end + 1] = b'\n';
+            let line = core::str::from_utf8(&line[..end + 2]).map_err(|_| core::fmt::Error)?;
+            f.write_str(line)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for HexDump<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(self, f)
+    }
+}
+
+/// Returns the given vector with each byte that isn't a printable ASCII
+/// character replaced by a period.
+#[inline(always)]
+fn printable(v: u8x8) -> u8x8 {
+    let keep = v
+        .greater_than(u8x8::splat(0x1f))
+        .and(v.less_than(u8x8::splat(0x7f)))
+        .to_u8x8_with(0xff);
+    (v & keep) | (u8x8::splat(b'.') & !keep)
+}
@@ -0,0 +1,22 @@
+use super::*;
+
+#[test]
+pub fn hexdump() {
+    let mut data = [0_u8; 40];
+    for (i, b) in data.iter_mut().enumerate() {
+        *b = (i as u8).wrapping_mul(7).wrapping_add(0x1a);
+    }
+    let got = format!("{}", hexdump::HexDump(&data));
+    let want = "\
+00000000  1a 21 28 2f 36 3d 44 4b  52 59 60 67 6e 75 7c 83  |.!(/6=DKRY`gnu|.|
+00000010  8a 91 98 9f a6 ad b4 bb  c2 c9 d0 d7 de e5 ec f3  |................|
+00000020  fa 01 08 0f 16 1d 24 2b                           |......$+|
+";
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn hexdump_empty() {
+    let got = format!("{}", hexdump::HexDump(&[]));
+    assert_eq!(got, "");
+}
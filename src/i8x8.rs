@@ -0,0 +1,142 @@
+use crate::vecmod::{ONLY_HIGH_BITS, msb_mask};
+use crate::{mask8x8, u8x8};
+
+/// A vector of eight `i8` values, which can have SIMD-like operations
+/// applied to them without any explicit SIMD instructions.
+///
+/// This type has the same representation as [`u8x8`], but its elements are
+/// interpreted as two's complement signed integers. Use
+/// [`u8x8::cast_i8x8`] and [`i8x8::cast_u8x8`] to reinterpret between the
+/// two without changing any bits.
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct i8x8 {
+    pub(crate) n: u64,
+}
+
+impl i8x8 {
+    /// An [`i8x8`] value where all eight elements are set to zero.
+    pub const ZEROES: Self = Self::new(0);
+
+    /// Converts an array of eight `i8` values into an [`i8x8`] value.
+    #[inline(always)]
+    pub const fn from_array(a: [i8; 8]) -> Self {
+        // Safety: [i8; 8] and [u8; 8] have the same size, and all bit
+        // patterns are valid for both.
+        let a = unsafe { core::mem::transmute::<[i8; 8], [u8; 8]>(a) };
+        Self::new(u64::from_ne_bytes(a))
+    }
+
+    #[inline(always)]
+    pub(crate) const fn new(n: u64) -> Self {
+        Self { n }
+    }
+
+    /// Returns an [`i8x8`] with `v` in all eight of its elements.
+    #[inline(always)]
+    pub const fn splat(v: i8) -> Self {
+        Self::new(v as u8 as u64 * 0x0101010101010101)
+    }
+
+    /// Converts the vector into an array of eight `i8` values.
+    #[inline(always)]
+    pub const fn to_array(self) -> [i8; 8] {
+        // Safety: [i8; 8] and [u8; 8] have the same size, and all bit
+        // patterns are valid for both.
+        unsafe { core::mem::transmute::<[u8; 8], [i8; 8]>(self.n.to_ne_bytes()) }
+    }
+
+    /// Flips the sign bit of each element, which maps the range of `i8`
+    /// onto the range of `u8` while preserving order, so that the unsigned
+    /// comparisons of [`u8x8`] can be reused.
+    #[inline(always)]
+    const fn biased(self) -> u8x8 {
+        u8x8::new(self.n ^ ONLY_HIGH_BITS)
+    }
+
+    /// Compares each element across both vectors and returns a mask value
+    /// where `true` represents equality and `false` represents inequality.
+    #[inline(always)]
+    pub const fn equals(self, other: Self) -> mask8x8 {
+        self.cast_u8x8().equals(other.cast_u8x8())
+    }
+
+    /// Compares each element across both vectors and returns a mask value
+    /// with elements set to `true` where the corresponding element in `self`
+    /// is less than the corresponding element in `other`.
+    ///
+    /// ```rust
+    /// # use eight_bytes::i8x8;
+    /// let a = i8x8::from_array([-128, -1, 0, 1, -5, 5, 127, -1]);
+    /// let b = i8x8::from_array([127, 0, 0, -1, -4, 4, 127, -128]);
+    /// assert_eq!(a.less_than(b).to_bitmask_le(), 0b0001_0011);
+    /// ```
+    #[inline(always)]
+    pub const fn less_than(self, other: Self) -> mask8x8 {
+        self.biased().less_than(other.biased())
+    }
+
+    /// Compares each element across both vectors and returns a mask value
+    /// with elements set to `true` where the corresponding element in `self`
+    /// is greater than the corresponding element in `other`.
+    ///
+    /// ```rust
+    /// # use eight_bytes::i8x8;
+    /// let a = i8x8::from_array([-128, -1, 0, 1, -5, 5, 127, -1]);
+    /// let b = i8x8::from_array([127, 0, 0, -1, -4, 4, 127, -128]);
+    /// assert_eq!(a.greater_than(b).to_bitmask_le(), 0b1010_1000);
+    /// ```
+    #[inline(always)]
+    pub const fn greater_than(self, other: Self) -> mask8x8 {
+        self.biased().greater_than(other.biased())
+    }
+
+    /// Reinterprets the bits of each element as `u8`, so that negative
+    /// values become values of 128 or greater.
+    #[inline(always)]
+    pub const fn cast_u8x8(self) -> u8x8 {
+        u8x8::new(self.n)
+    }
+
+    /// Converts each element to `u8`, clamping negative values to zero.
+    ///
+    /// ```rust
+    /// # use eight_bytes::{i8x8, u8x8};
+    /// let v = i8x8::from_array([-128, -1, 0, 1, 2, 64, 126, 127]);
+    /// assert_eq!(v.to_u8x8_saturating().to_array(), [0, 0, 0, 1, 2, 64, 126, 127]);
+    /// ```
+    #[inline(always)]
+    pub const fn to_u8x8_saturating(self) -> u8x8 {
+        u8x8::new(self.n & !msb_mask(self.n & ONLY_HIGH_BITS))
+    }
+}
+
+impl u8x8 {
+    /// Reinterprets the bits of each element as `i8`, so that values of 128
+    /// or greater become negative.
+    #[inline(always)]
+    pub const fn cast_i8x8(self) -> i8x8 {
+        i8x8::new(self.n)
+    }
+
+    /// Converts each element to `i8`, clamping values greater than 127
+    /// to 127.
+    ///
+    /// ```rust
+    /// # use eight_bytes::{i8x8, u8x8};
+    /// let v = u8x8::from_array([0, 1, 126, 127, 128, 129, 200, 255]);
+    /// assert_eq!(v.to_i8x8_saturating().to_array(), [0, 1, 126, 127, 127, 127, 127, 127]);
+    /// ```
+    #[inline(always)]
+    pub const fn to_i8x8_saturating(self) -> i8x8 {
+        let over = msb_mask(self.n & ONLY_HIGH_BITS);
+        i8x8::new((self.n & !over) | (over & !ONLY_HIGH_BITS))
+    }
+}
+
+impl core::fmt::Debug for i8x8 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("i8x8").field(&self.to_array()).finish()
+    }
+}
@@ -0,0 +1,56 @@
+use super::*;
+
+#[test]
+pub fn from_array() {
+    let v = i8x8::from_array([-128, -1, 0, 1, 2, 64, 126, 127]);
+    assert_eq!(v.to_array(), [-128, -1, 0, 1, 2, 64, 126, 127]);
+    assert_eq!(i8x8::splat(-3).to_array(), [-3; 8]);
+}
+
+#[test]
+pub fn casts() {
+    let v = u8x8::from_array([0, 1, 127, 128, 129, 200, 254, 255]);
+    let s = v.cast_i8x8();
+    assert_eq!(s.to_array(), [0, 1, 127, -128, -127, -56, -2, -1]);
+    assert_eq!(s.cast_u8x8(), v);
+}
+
+#[test]
+pub fn to_i8x8_saturating() {
+    let v = u8x8::from_array([0, 1, 127, 128, 129, 200, 254, 255]);
+    let got = v.to_i8x8_saturating();
+    let want = i8x8::from_array([0, 1, 127, 127, 127, 127, 127, 127]);
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn to_u8x8_saturating() {
+    let v = i8x8::from_array([-128, -127, -1, 0, 1, 100, 126, 127]);
+    let got = v.to_u8x8_saturating();
+    let want = u8x8::from_array([0, 0, 0, 0, 1, 100, 126, 127]);
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn comparisons() {
+    let a = i8x8::from_array([-128, -1, 0, 1, -5, 5, 127, -1]);
+    let b = i8x8::from_array([127, 0, 0, -1, -4, 4, 127, -128]);
+    assert_eq!(
+        a.equals(b).to_array(),
+        [false, false, true, false, false, false, true, false],
+    );
+    assert_eq!(
+        a.less_than(b).to_array(),
+        [true, true, false, false, true, false, false, false],
+    );
+    assert_eq!(
+        a.greater_than(b).to_array(),
+        [false, false, false, true, false, true, false, true],
+    );
+
+    // The unsigned comparisons give different answers for the same bits.
+    assert_eq!(
+        a.cast_u8x8().less_than(b.cast_u8x8()).to_array(),
+        [false, false, false, true, true, false, false, false],
+    );
+}
@@ -12,18 +12,133 @@
 //!
 //! This library is `no_std`-compatible and intended for CPU architectures that
 //! can perform arithmetic operations efficiently on 64-bit values.
+//!
+//! The following optional features are available:
+//!
+//! - `alloc`: adds variants of some functions that return their results in
+//!   newly-allocated vectors, such as `rle::encode_to_vec`.
+//! - `bytemuck`: implements `bytemuck::Pod` and `bytemuck::Zeroable` for
+//!   `u8x8`, so that slices of vectors can be safely cast to and from slices
+//!   of bytes.
+//! - `defmt`: implements `defmt::Format` for `u8x8` and `mask8x8`, for
+//!   logging from embedded targets.
+//! - `rand`: implements `rand::distr::Distribution<u8x8>` for
+//!   `rand::distr::StandardUniform` and `rand::Fill` for `u8x8`, for generating
+//!   vectors of random elements.
+//! - `reference`: exposes the `reference` module, which contains simple
+//!   per-element implementations of the vector operations for use in
+//!   differential testing.
+//! - `serde`: implements `Serialize` and `Deserialize` for `u8x8` and
+//!   `mask8x8`, using arrays of eight elements as the serialized form.
+//! - `zerocopy`: implements the `zerocopy` traits for `u8x8`, and the
+//!   subset of them that allow converting to bytes for `mask8x8`.
 #![cfg_attr(not(test), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[path = "i8x8.rs"]
+mod ivecmod;
+#[path = "mask16x4.rs"]
+mod mask16mod;
+#[path = "mask32x2.rs"]
+mod mask32mod;
 #[path = "mask8x8.rs"]
 mod maskmod;
+#[path = "traits.rs"]
+mod traitmod;
+#[path = "u16x4.rs"]
+mod vec16mod;
 #[path = "u8x8.rs"]
 mod vecmod;
+#[path = "mask8x16.rs"]
+mod widemaskmod;
+#[path = "u8x16.rs"]
+mod widevecmod;
 
+pub use ivecmod::*;
+pub use mask16mod::*;
+pub use mask32mod::*;
 pub use maskmod::*;
+pub use traitmod::*;
+pub use vec16mod::*;
 pub use vecmod::*;
+pub use widemaskmod::*;
+pub use widevecmod::*;
+
+pub mod bitslice;
+pub mod checksum;
+pub mod delta;
+pub mod endian;
+pub mod find;
+pub mod hex;
+pub mod hexdump;
+pub mod pixel;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+pub mod rle;
+pub mod sort;
+pub mod stats;
 
 #[cfg(test)]
 mod u8x8_tests;
 
 #[cfg(test)]
 mod mask8x8_tests;
+
+#[cfg(test)]
+mod bitslice_tests;
+
+#[cfg(test)]
+mod delta_tests;
+
+#[cfg(test)]
+mod rle_tests;
+
+#[cfg(test)]
+mod checksum_tests;
+
+#[cfg(test)]
+mod hexdump_tests;
+
+#[cfg(test)]
+mod find_tests;
+
+#[cfg(test)]
+mod hex_tests;
+
+#[cfg(test)]
+mod reference_tests;
+
+#[cfg(test)]
+mod pixel_tests;
+
+#[cfg(test)]
+mod stats_tests;
+
+#[cfg(test)]
+mod endian_tests;
+
+#[cfg(test)]
+mod u16x4_tests;
+
+#[cfg(test)]
+mod i8x8_tests;
+
+#[cfg(test)]
+mod sort_tests;
+
+#[cfg(test)]
+mod u8x16_tests;
+
+#[cfg(test)]
+mod mask8x16_tests;
+
+#[cfg(test)]
+mod traits_tests;
+
+#[cfg(test)]
+mod mask16x4_tests;
+
+#[cfg(test)]
+mod mask32x2_tests;
@@ -0,0 +1,202 @@
+use crate::u16x4;
+use crate::vec16mod::{ALL_ONES_16, reverse_lanes};
+
+/// A vector of four `bool` values, which can have SIMD-like operations
+/// applied to them without any explicit SIMD instructions.
+///
+/// This type is really just a [`u64`], but its methods interpret it as four
+/// [`bool`] values each occupying a 16-bit lane. It is the mask type for
+/// [`u16x4`].
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct mask16x4 {
+    pub(crate) n: u64,
+}
+
+impl mask16x4 {
+    /// A [`mask16x4`] value where all four elements are set to `false`.
+    pub const ALL_FALSE: Self = Self::new(0);
+
+    /// A [`mask16x4`] value where all four elements are set to `true`.
+    pub const ALL_TRUE: Self = Self::new(ALL_ONES_16);
+
+    /// Converts the given array into a [`mask16x4`].
+    #[inline(always)]
+    pub const fn from_array(a: [bool; 4]) -> Self {
+        let lanes = [a[0] as u16, a[1] as u16, a[2] as u16, a[3] as u16];
+        Self::new(u16x4::from_array(lanes).n)
+    }
+
+    #[inline(always)]
+    const fn from_bitmask_raw(mask: u8) -> u64 {
+        let m = (mask & 0xf) as u64;
+        (m | (m << 15) | (m << 30) | (m << 45)) & ALL_ONES_16
+    }
+
+    /// Converts the given bitmask into a [`mask16x4`] by treating a set bit
+    /// as `true` and an unset bit as `false`. The least significant bit
+    /// appears in the first element, and bits above the fourth are ignored.
+    ///
+    /// ```rust
+    /// # use eight_bytes::{mask16x4};
+    /// let mask = mask16x4::from_bitmask_le(0b1101);
+    /// assert_eq!(mask.to_array(), [true, false, true, true]);
+    /// ```
+    #[inline(always)]
+    pub const fn from_bitmask_le(mask: u8) -> Self {
+        Self::new(u16x4::from_le_lanes(Self::from_bitmask_raw(mask)).n)
+    }
+
+    /// Converts the given bitmask into a [`mask16x4`] by treating a set bit
+    /// as `true` and an unset bit as `false`. The least significant bit
+    /// appears in the last element, and bits above the fourth are ignored.
+    ///
+    /// ```rust
+    /// # use eight_bytes::{mask16x4};
+    /// let mask = mask16x4::from_bitmask_be(0b1101);
+    /// assert_eq!(mask.to_array(), [true, true, false, true]);
+    /// ```
+    #[inline(always)]
+    pub const fn from_bitmask_be(mask: u8) -> Self {
+        let raw = reverse_lanes(Self::from_bitmask_raw(mask));
+        Self::new(u16x4::from_le_lanes(raw).n)
+    }
+
+    #[inline(always)]
+    pub(crate) const fn new(n: u64) -> Self {
+        Self { n }
+    }
+
+    /// Converts the vector into an array of four `bool` values.
+    #[inline(always)]
+    pub const fn to_array(self) -> [bool; 4] {
+        let lanes = self.to_u16x4().to_array();
+        [lanes[0] != 0, lanes[1] != 0, lanes[2] != 0, lanes[3] != 0]
+    }
+
+    #[inline(always)]
+    const fn to_bitmask_raw(raw: u64) -> u8 {
+        ((raw | (raw >> 15) | (raw >> 30) | (raw >> 45)) & 0xf) as u8
+    }
+
+    /// Converts the vector into a bitmask where the first element is in
+    /// the least significant bit.
+    #[inline(always)]
+    pub const fn to_bitmask_le(self) -> u8 {
+        Self::to_bitmask_raw(self.to_u16x4().to_le_lanes())
+    }
+
+    /// Converts the vector into a bitmask where the first element is in
+    /// the most significant of the four low bits.
+    #[inline(always)]
+    pub const fn to_bitmask_be(self) -> u8 {
+        Self::to_bitmask_raw(reverse_lanes(self.to_u16x4().to_le_lanes()))
+    }
+
+    /// Returns a [`u16x4`] representation of the mask where true elements
+    /// are represented as `1` and false elements are represented as `0`.
+    #[inline(always)]
+    pub const fn to_u16x4(self) -> u16x4 {
+        u16x4::new(self.n)
+    }
+
+    /// Returns a [`u16x4`] representation of the mask where true elements
+    /// are represented as `v` and false elements are represented as `0`.
+    #[inline(always)]
+    pub const fn to_u16x4_with(self, v: u16) -> u16x4 {
+        u16x4::new(self.n * v as u64)
+    }
+
+    /// Computes the complement of each element in the vector.
+    #[inline(always)]
+    pub const fn not(self) -> Self {
+        Self::new(self.n ^ ALL_ONES_16)
+    }
+
+    /// Computes a logical OR result for each element across both vectors.
+    #[inline(always)]
+    pub const fn or(self, other: Self) -> Self {
+        Self::new(self.n | other.n)
+    }
+
+    /// Computes a logical AND result for each element across both vectors.
+    #[inline(always)]
+    pub const fn and(self, other: Self) -> Self {
+        Self::new(self.n & other.n)
+    }
+
+    /// Builds a [`u16x4`] by selecting one of the two given values for each
+    /// element corresponding to the elements in the mask.
+    #[inline(always)]
+    pub const fn select(self, true_value: u16, false_value: u16) -> u16x4 {
+        let true_value = u16x4::splat(true_value).n;
+        let false_value = u16x4::splat(false_value).n;
+        let mask = self.n * 0xffff;
+        u16x4::new((true_value & mask) | (false_value & !mask))
+    }
+
+    /// Returns the number of elements in the mask that are set to `true`.
+    #[inline(always)]
+    pub const fn count_true(self) -> u32 {
+        self.n.count_ones()
+    }
+
+    /// Returns the number of elements in the mask that are set to `false`.
+    #[inline(always)]
+    pub const fn count_false(self) -> u32 {
+        4 - self.n.count_ones()
+    }
+}
+
+impl core::fmt::Debug for mask16x4 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("mask16x4").field(&self.to_array()).finish()
+    }
+}
+
+impl core::ops::Not for mask16x4 {
+    type Output = Self;
+
+    /// Implements the unary `!` operator using [`Self::not`].
+    #[inline(always)]
+    fn not(self) -> Self {
+        self.not()
+    }
+}
+
+impl core::ops::BitOr for mask16x4 {
+    type Output = Self;
+
+    /// Implements the `|` operator using [`Self::or`].
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self {
+        self.or(rhs)
+    }
+}
+
+impl core::ops::BitOrAssign for mask16x4 {
+    /// Implements the `|=` operator using [`Self::or`].
+    #[inline(always)]
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.or(rhs);
+    }
+}
+
+impl core::ops::BitAnd for mask16x4 {
+    type Output = Self;
+
+    /// Implements the `&` operator using [`Self::and`].
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self {
+        self.and(rhs)
+    }
+}
+
+impl core::ops::BitAndAssign for mask16x4 {
+    /// Implements the `&=` operator using [`Self::and`].
+    #[inline(always)]
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.and(rhs);
+    }
+}
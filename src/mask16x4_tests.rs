@@ -0,0 +1,34 @@
+use super::*;
+
+#[test]
+pub fn bitmasks() {
+    for bits in 0..16_u8 {
+        let a = core::array::from_fn(|i| bits & (1 << i) != 0);
+        let mask = mask16x4::from_array(a);
+        assert_eq!(mask.to_array(), a);
+        assert_eq!(mask.to_bitmask_le(), bits);
+        assert_eq!(mask16x4::from_bitmask_le(bits), mask);
+        let be = bits.reverse_bits() >> 4;
+        assert_eq!(mask.to_bitmask_be(), be);
+        assert_eq!(mask16x4::from_bitmask_be(be), mask);
+        assert_eq!(mask.count_true(), bits.count_ones());
+        assert_eq!(mask.count_false(), 4 - bits.count_ones());
+    }
+}
+
+#[test]
+pub fn select() {
+    let mask = mask16x4::from_array([true, false, false, true]);
+    assert_eq!(mask.select(0xffff, 7).to_array(), [0xffff, 7, 7, 0xffff]);
+    assert_eq!(mask.to_u16x4_with(300).to_array(), [300, 0, 0, 300]);
+    assert_eq!(mask.not().to_u16x4().to_array(), [0, 1, 1, 0]);
+}
+
+#[test]
+pub fn comparisons() {
+    let a = u16x4::from_array([1, 0x8000, 0xffff, 0x0100]);
+    let b = u16x4::from_array([1, 0x7fff, 0xfffe, 0x0101]);
+    assert_eq!(a.equals(b).to_array(), [true, false, false, false]);
+    assert_eq!(a.less_than(b).to_array(), [false, false, false, true]);
+    assert_eq!(a.greater_than(b).to_array(), [false, true, true, false]);
+}
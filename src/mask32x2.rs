@@ -0,0 +1,209 @@
+/// A vector of two `bool` values, which can have SIMD-like operations
+/// applied to them without any explicit SIMD instructions.
+///
+/// This type is really just a [`u64`], but its methods interpret it as two
+/// [`bool`] values each occupying a 32-bit lane, for use with code that
+/// treats a `u64` as a pair of `u32` values.
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct mask32x2 {
+    pub(crate) n: u64,
+}
+
+impl mask32x2 {
+    /// A [`mask32x2`] value where both elements are set to `false`.
+    pub const ALL_FALSE: Self = Self::new(0);
+
+    /// A [`mask32x2`] value where both elements are set to `true`.
+    pub const ALL_TRUE: Self = Self::new(ALL_ONES_32);
+
+    /// Converts the given array into a [`mask32x2`].
+    #[inline(always)]
+    pub const fn from_array(a: [bool; 2]) -> Self {
+        Self::new(u64::from_ne_bytes(Self::join(a[0] as u32, a[1] as u32)))
+    }
+
+    /// Converts the given bitmask into a [`mask32x2`] by treating a set bit
+    /// as `true` and an unset bit as `false`. The least significant bit
+    /// appears in the first element, and bits above the second are ignored.
+    ///
+    /// ```rust
+    /// # use eight_bytes::{mask32x2};
+    /// let mask = mask32x2::from_bitmask_le(0b01);
+    /// assert_eq!(mask.to_array(), [true, false]);
+    /// ```
+    #[inline(always)]
+    pub const fn from_bitmask_le(mask: u8) -> Self {
+        Self::from_array([mask & 1 != 0, mask & 2 != 0])
+    }
+
+    /// Converts the given bitmask into a [`mask32x2`] by treating a set bit
+    /// as `true` and an unset bit as `false`. The least significant bit
+    /// appears in the last element, and bits above the second are ignored.
+    ///
+    /// ```rust
+    /// # use eight_bytes::{mask32x2};
+    /// let mask = mask32x2::from_bitmask_be(0b01);
+    /// assert_eq!(mask.to_array(), [false, true]);
+    /// ```
+    #[inline(always)]
+    pub const fn from_bitmask_be(mask: u8) -> Self {
+        Self::from_array([mask & 2 != 0, mask & 1 != 0])
+    }
+
+    #[inline(always)]
+    pub(crate) const fn new(n: u64) -> Self {
+        Self { n }
+    }
+
+    /// Converts the vector into an array of two `bool` values.
+    #[inline(always)]
+    pub const fn to_array(self) -> [bool; 2] {
+        let [a, b] = self.to_u32_array();
+        [a != 0, b != 0]
+    }
+
+    /// Converts the vector into a bitmask where the first element is in
+    /// the least significant bit.
+    #[inline(always)]
+    pub const fn to_bitmask_le(self) -> u8 {
+        let [a, b] = self.to_u32_array();
+        (a | (b << 1)) as u8
+    }
+
+    /// Converts the vector into a bitmask where the first element is in
+    /// the most significant of the two low bits.
+    #[inline(always)]
+    pub const fn to_bitmask_be(self) -> u8 {
+        let [a, b] = self.to_u32_array();
+        ((a << 1) | b) as u8
+    }
+
+    /// Returns the raw `u64` representation of the mask, where each
+    /// true element is a 32-bit lane containing `v` and each false element
+    /// is a lane containing zero.
+    ///
+    /// The lanes are in the target's native byte order, so this is the same
+    /// as reinterpreting a `[u32; 2]` array as `u64`.
+    #[inline(always)]
+    pub const fn to_u64_with(self, v: u32) -> u64 {
+        self.n * v as u64
+    }
+
+    /// Computes the complement of each element in the vector.
+    #[inline(always)]
+    pub const fn not(self) -> Self {
+        Self::new(self.n ^ ALL_ONES_32)
+    }
+
+    /// Computes a logical OR result for each element across both vectors.
+    #[inline(always)]
+    pub const fn or(self, other: Self) -> Self {
+        Self::new(self.n | other.n)
+    }
+
+    /// Computes a logical AND result for each element across both vectors.
+    #[inline(always)]
+    pub const fn and(self, other: Self) -> Self {
+        Self::new(self.n & other.n)
+    }
+
+    /// Builds a pair of `u32` values by selecting one of the two given
+    /// values for each element corresponding to the elements in the mask.
+    ///
+    /// ```rust
+    /// # use eight_bytes::{mask32x2};
+    /// let mask = mask32x2::from_array([false, true]);
+    /// assert_eq!(mask.select(0xdead_beef, 7), [7, 0xdead_beef]);
+    /// ```
+    #[inline(always)]
+    pub const fn select(self, true_value: u32, false_value: u32) -> [u32; 2] {
+        let true_value = true_value as u64 * ALL_ONES_32;
+        let false_value = false_value as u64 * ALL_ONES_32;
+        let mask = self.n * 0xffff_ffff;
+        Self::new((true_value & mask) | (false_value & !mask)).to_u32_array()
+    }
+
+    /// Returns the number of elements in the mask that are set to `true`.
+    #[inline(always)]
+    pub const fn count_true(self) -> u32 {
+        self.n.count_ones()
+    }
+
+    /// Returns the number of elements in the mask that are set to `false`.
+    #[inline(always)]
+    pub const fn count_false(self) -> u32 {
+        2 - self.n.count_ones()
+    }
+
+    #[inline(always)]
+    const fn join(a: u32, b: u32) -> [u8; 8] {
+        let (a, b) = (a.to_ne_bytes(), b.to_ne_bytes());
+        [a[0], a[1], a[2], a[3], b[0], b[1], b[2], b[3]]
+    }
+
+    #[inline(always)]
+    const fn to_u32_array(self) -> [u32; 2] {
+        let n = self.n.to_ne_bytes();
+        [
+            u32::from_ne_bytes([n[0], n[1], n[2], n[3]]),
+            u32::from_ne_bytes([n[4], n[5], n[6], n[7]]),
+        ]
+    }
+}
+
+impl core::fmt::Debug for mask32x2 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("mask32x2").field(&self.to_array()).finish()
+    }
+}
+
+impl core::ops::Not for mask32x2 {
+    type Output = Self;
+
+    /// Implements the unary `!` operator using [`Self::not`].
+    #[inline(always)]
+    fn not(self) -> Self {
+        self.not()
+    }
+}
+
+impl core::ops::BitOr for mask32x2 {
+    type Output = Self;
+
+    /// Implements the `|` operator using [`Self::or`].
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self {
+        self.or(rhs)
+    }
+}
+
+impl core::ops::BitOrAssign for mask32x2 {
+    /// Implements the `|=` operator using [`Self::or`].
+    #[inline(always)]
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.or(rhs);
+    }
+}
+
+impl core::ops::BitAnd for mask32x2 {
+    type Output = Self;
+
+    /// Implements the `&` operator using [`Self::and`].
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self {
+        self.and(rhs)
+    }
+}
+
+impl core::ops::BitAndAssign for mask32x2 {
+    /// Implements the `&=` operator using [`Self::and`].
+    #[inline(always)]
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.and(rhs);
+    }
+}
+
+/// Raw representation of a mask where both elements are `true`.
+const ALL_ONES_32: u64 = 0x0000000100000001;
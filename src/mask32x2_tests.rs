@@ -0,0 +1,27 @@
+use super::*;
+
+#[test]
+pub fn bitmasks() {
+    for bits in 0..4_u8 {
+        let a = [bits & 1 != 0, bits & 2 != 0];
+        let mask = mask32x2::from_array(a);
+        assert_eq!(mask.to_array(), a);
+        assert_eq!(mask.to_bitmask_le(), bits);
+        assert_eq!(mask32x2::from_bitmask_le(bits), mask);
+        let be = bits.reverse_bits() >> 6;
+        assert_eq!(mask.to_bitmask_be(), be);
+        assert_eq!(mask32x2::from_bitmask_be(be), mask);
+        assert_eq!(mask.count_true(), bits.count_ones());
+        assert_eq!(mask.count_false(), 2 - bits.count_ones());
+    }
+}
+
+#[test]
+pub fn select() {
+    let mask = mask32x2::from_array([true, false]);
+    assert_eq!(mask.select(0xffff_ffff, 9), [0xffff_ffff, 9]);
+    assert_eq!(mask.not().select(0xffff_ffff, 9), [9, 0xffff_ffff]);
+    let raw = mask.to_u64_with(0x1234_5678);
+    assert_eq!(raw.to_ne_bytes()[..4], 0x1234_5678_u32.to_ne_bytes());
+    assert_eq!(raw.to_ne_bytes()[4..], [0; 4]);
+}
@@ -0,0 +1,208 @@
+use crate::ALL_ONES_128;
+use crate::mask8x8;
+use crate::u8x16;
+
+/// A vector of sixteen `bool` values, which can have SIMD-like operations
+/// applied to them without any explicit SIMD instructions.
+///
+/// This type is really just a [`u128`], but its methods interpret it as
+/// sixteen [`bool`] values where the same operation is applied to all
+/// sixteen values at once. It is the mask type for [`u8x16`].
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct mask8x16 {
+    pub(crate) n: u128,
+}
+
+impl mask8x16 {
+    /// A [`mask8x16`] value where all sixteen elements are set to `false`.
+    pub const ALL_FALSE: Self = Self::new(0);
+
+    /// A [`mask8x16`] value where all sixteen elements are set to `true`.
+    pub const ALL_TRUE: Self = Self::new(ALL_ONES_128);
+
+    /// Converts the given array into a [`mask8x16`].
+    #[inline(always)]
+    pub const fn from_array(a: [bool; 16]) -> Self {
+        // Safety: The Rust spec guarantees that `bool` is has size 1 and
+        // alignment 1, and that true is 0x01 while false is 0x00. The
+        // layout matches u8 and both bit patterns are valid for u8.
+        let a: [u8; 16] = unsafe { core::mem::transmute(a) };
+        Self {
+            n: u128::from_ne_bytes(a),
+        }
+    }
+
+    #[inline(always)]
+    const fn from_bitmask_raw(mask: u16) -> u128 {
+        let lo = mask8x8::from_bitmask_raw(mask as u8) as u128;
+        let hi = mask8x8::from_bitmask_raw((mask >> 8) as u8) as u128;
+        lo | (hi << 64)
+    }
+
+    /// Converts the given bitmask into a [`mask8x16`] by treating a set bit
+    /// as `true` and an unset bit as `false`. The least significant bit
+    /// appears in the first element.
+    ///
+    /// ```rust
+    /// # use eight_bytes::{mask8x16};
+    /// let mask = mask8x16::from_bitmask_le(0b10000000_00000011);
+    /// assert_eq!(mask.to_array()[..3], [true, true, false]);
+    /// assert_eq!(mask.to_array()[15], true);
+    /// ```
+    #[inline(always)]
+    pub const fn from_bitmask_le(mask: u16) -> Self {
+        Self::new(Self::from_bitmask_raw(mask).to_le())
+    }
+
+    /// Converts the given bitmask into a [`mask8x16`] by treating a set bit
+    /// as `true` and an unset bit as `false`. The least significant bit
+    /// appears in the last element.
+    #[inline(always)]
+    pub const fn from_bitmask_be(mask: u16) -> Self {
+        Self::new(Self::from_bitmask_raw(mask).to_be())
+    }
+
+    #[inline(always)]
+    pub(crate) const fn new(n: u128) -> Self {
+        Self { n }
+    }
+
+    /// Converts the vector into an array of sixteen `bool` values.
+    #[inline(always)]
+    pub const fn to_array(self) -> [bool; 16] {
+        // Safety: The Rust spec guarantees that `bool` is has size 1 and
+        // alignment 1, and that true is 0x01 while false is 0x00. The
+        // layout matches u8 and both bit patterns are valid for u8.
+        let u8s = self.n.to_ne_bytes();
+        unsafe { core::mem::transmute(u8s) }
+    }
+
+    #[inline(always)]
+    const fn to_bitmask_raw(raw: u128) -> u16 {
+        let lo = mask8x8::to_bitmask_raw(raw as u64) as u16;
+        let hi = mask8x8::to_bitmask_raw((raw >> 64) as u64) as u16;
+        lo | (hi << 8)
+    }
+
+    /// Converts the vector into a bitmask where the first element is in
+    /// the least significant bit.
+    #[inline(always)]
+    pub const fn to_bitmask_le(self) -> u16 {
+        Self::to_bitmask_raw(self.n.to_le())
+    }
+
+    /// Converts the vector into a bitmask where the first element is in
+    /// the most significant bit.
+    #[inline(always)]
+    pub const fn to_bitmask_be(self) -> u16 {
+        Self::to_bitmask_raw(self.n.to_be())
+    }
+
+    /// Returns a [`u8x16`] representation of the mask where true elements
+    /// are represented as `0x01` and false elements are represented as `0x00`.
+    #[inline(always)]
+    pub const fn to_u8x16(self) -> u8x16 {
+        u8x16::new(self.n)
+    }
+
+    /// Returns a [`u8x16`] representation of the mask where true elements
+    /// are represented as `v` and false elements are represented as `0x00`.
+    #[inline(always)]
+    pub const fn to_u8x16_with(self, v: u8) -> u8x16 {
+        u8x16::new(self.n * v as u128)
+    }
+
+    /// Computes the complement of each element in the vector.
+    #[inline(always)]
+    pub const fn not(self) -> Self {
+        Self::new(self.n ^ ALL_ONES_128)
+    }
+
+    /// Computes a logical OR result for each element across both vectors.
+    #[inline(always)]
+    pub const fn or(self, other: Self) -> Self {
+        Self::new(self.n | other.n)
+    }
+
+    /// Computes a logical AND result for each element across both vectors.
+    #[inline(always)]
+    pub const fn and(self, other: Self) -> Self {
+        Self::new(self.n & other.n)
+    }
+
+    /// Builds a [`u8x16`] by selecting one of the two given values for each
+    /// element corresponding to the elements in the mask.
+    #[inline(always)]
+    pub const fn select(self, true_value: u8, false_value: u8) -> u8x16 {
+        let true_value = u8x16::splat(true_value).n;
+        let false_value = u8x16::splat(false_value).n;
+        let mask = self.n * 0xff;
+        u8x16::new((true_value & mask) | (false_value & !mask))
+    }
+
+    /// Returns the number of elements in the mask that are set to `true`.
+    #[inline(always)]
+    pub const fn count_true(self) -> u32 {
+        self.n.count_ones()
+    }
+
+    /// Returns the number of elements in the mask that are set to `false`.
+    #[inline(always)]
+    pub const fn count_false(self) -> u32 {
+        16 - self.n.count_ones()
+    }
+}
+
+impl core::fmt::Debug for mask8x16 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("mask8x16").field(&self.to_array()).finish()
+    }
+}
+
+impl core::ops::Not for mask8x16 {
+    type Output = Self;
+
+    /// Implements the unary `!` operator using [`Self::not`].
+    #[inline(always)]
+    fn not(self) -> Self {
+        self.not()
+    }
+}
+
+impl core::ops::BitOr for mask8x16 {
+    type Output = Self;
+
+    /// Implements the `|` operator using [`Self::or`].
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self {
+        self.or(rhs)
+    }
+}
+
+impl core::ops::BitOrAssign for mask8x16 {
+    /// Implements the `|=` operator using [`Self::or`].
+    #[inline(always)]
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.or(rhs);
+    }
+}
+
+impl core::ops::BitAnd for mask8x16 {
+    type Output = Self;
+
+    /// Implements the `&` operator using [`Self::and`].
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self {
+        self.and(rhs)
+    }
+}
+
+impl core::ops::BitAndAssign for mask8x16 {
+    /// Implements the `&=` operator using [`Self::and`].
+    #[inline(always)]
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.and(rhs);
+    }
+}
@@ -0,0 +1,32 @@
+use super::*;
+
+#[test]
+pub fn bitmasks() {
+    let mut a = [false; 16];
+    for i in [0, 3, 8, 9, 15] {
+        a[i] = true;
+    }
+    let mask = mask8x16::from_array(a);
+    assert_eq!(mask.to_bitmask_le(), 0b1000_0011_0000_1001);
+    assert_eq!(mask.to_bitmask_be(), 0b1001_0000_1100_0001);
+    assert_eq!(mask8x16::from_bitmask_le(0b1000_0011_0000_1001), mask);
+    assert_eq!(mask8x16::from_bitmask_be(0b1001_0000_1100_0001), mask);
+}
+
+#[test]
+pub fn counts() {
+    let mask = mask8x16::from_bitmask_le(0b0110_0000_1111_0001);
+    assert_eq!(mask.count_true(), 7);
+    assert_eq!(mask.count_false(), 9);
+    assert_eq!(mask.not().count_true(), 9);
+    assert_eq!(mask8x16::ALL_TRUE.count_true(), 16);
+}
+
+#[test]
+pub fn select() {
+    let mask = mask8x16::from_bitmask_le(0b0000_0001_1000_0010);
+    let got = mask.select(0xff, 1);
+    let want = u8x16::from_array([1, 0xff, 1, 1, 1, 1, 1, 0xff, 0xff, 1, 1, 1, 1, 1, 1, 1]);
+    assert_eq!(got, want);
+    assert_eq!(mask.to_u8x16_with(7).reduce_sum(), 21);
+}
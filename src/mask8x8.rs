@@ -8,7 +8,13 @@ use crate::{ALL_ONES, u8x8};
 /// once.
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+// Not every bit pattern is a valid mask, so with zerocopy a mask can only
+// be converted to bytes, not from them.
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout)
+)]
 pub struct mask8x8 {
     pub(crate) n: u64,
 }
@@ -33,11 +39,41 @@ impl mask8x8 {
     }
 
     #[inline(always)]
-    const fn from_bitmask_raw(mask: u8) -> u64 {
+    pub(crate) const fn from_bitmask_raw(mask: u8) -> u64 {
         let raw = mask as u64;
         (((raw & 0x55) * 0x02040810204081) | ((raw & 0xaa) * 0x02040810204081)) & ALL_ONES
     }
 
+    /// Creates a mask from a raw `u64` in which each byte represents one
+    /// element in the platform's native byte order, treating any nonzero
+    /// byte as `true` and a zero byte as `false`.
+    ///
+    /// This is intended for custom SWAR techniques that produce a result
+    /// directly in a `u64`. [`Self::to_bits`] returns the representation
+    /// that this type uses internally.
+    ///
+    /// ```rust
+    /// # use eight_bytes::mask8x8;
+    /// let mask = mask8x8::from_bits(u64::from_ne_bytes([0, 1, 0x80, 0xff, 0, 0, 0x10, 0]));
+    /// assert_eq!(mask.to_array(), [false, true, true, true, false, false, true, false]);
+    /// ```
+    #[inline(always)]
+    pub const fn from_bits(n: u64) -> Self {
+        const LOW_SEVEN: u64 = 0x7f7f7f7f7f7f7f7f;
+        // Adding to the low seven bits of each byte carries into the most
+        // significant bit if any of them were set.
+        let any = (((n & LOW_SEVEN) + LOW_SEVEN) | n) & !LOW_SEVEN;
+        Self::new(any >> 7)
+    }
+
+    /// Returns the raw representation of the mask as a `u64`, in which each
+    /// byte represents one element in the platform's native byte order, with
+    /// `true` as `0x01` and `false` as `0x00`.
+    #[inline(always)]
+    pub const fn to_bits(self) -> u64 {
+        self.n
+    }
+
     /// Converts the given bitmask into a [`mask8x8`] by treating a set bit
     /// as `true` and an unset bit as `false`. The least significant bit
     /// appears in the first element.
@@ -84,7 +120,7 @@ impl mask8x8 {
     }
 
     #[inline(always)]
-    const fn to_bitmask_raw(raw: u64) -> u8 {
+    pub(crate) const fn to_bitmask_raw(raw: u64) -> u8 {
         const MASK: u64 = 0x0102040810204080;
         (raw.wrapping_mul(MASK) >> 56) as u8
     }
@@ -114,7 +150,7 @@ impl mask8x8 {
     /// are represented as `v` and false elements are represented as `0x00`.
     #[inline(always)]
     pub const fn to_u8x8_with(self, v: u8) -> u8x8 {
-        u8x8::new(u8x8::new(self.n).n * u8x8::splat(v).n)
+        u8x8::new(self.n * v as u64)
     }
 
     /// Computes the complement of each element in the vector.
@@ -172,6 +208,29 @@ impl mask8x8 {
     }
 }
 
+/// Serializes as an array of eight `bool` values in index order, so the
+/// serialized form doesn't depend on the platform's byte order.
+#[cfg(feature = "serde")]
+impl serde::Serialize for mask8x8 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_array().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for mask8x8 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <[bool; 8]>::deserialize(deserializer).map(Self::from_array)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for mask8x8 {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "mask8x8({=[?; 8]})", self.to_array())
+    }
+}
+
 impl core::fmt::Debug for mask8x8 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("mask8x8").field(&self.to_array()).finish()
@@ -54,6 +54,14 @@ pub fn and() {
     assert_eq!(got, want);
 }
 
+#[test]
+pub fn to_u8x8_with() {
+    let mask = mask8x8::from_array([true, false, true, false, true, true, false, false]);
+    let got = mask.to_u8x8_with(0xfe);
+    let want = u8x8::from_array([0xfe, 0, 0xfe, 0, 0xfe, 0xfe, 0, 0]);
+    assert_eq!(got, want);
+}
+
 #[test]
 pub fn select() {
     let choices = mask8x8::from_array([true, false, true, false, true, true, false, false]);
@@ -73,3 +81,50 @@ pub fn count_false() {
     let choices = mask8x8::from_array([true, false, true, false, true, true, true, false]);
     assert_eq!(choices.count_false(), 3);
 }
+
+#[test]
+pub fn bits() {
+    let mask = mask8x8::from_array([true, false, true, false, true, true, true, false]);
+    assert_eq!(mask.to_bits(), u64::from_ne_bytes([1, 0, 1, 0, 1, 1, 1, 0]));
+    assert_eq!(mask8x8::from_bits(mask.to_bits()), mask);
+    assert_eq!(mask8x8::from_bits(u64::MAX), mask8x8::ALL_TRUE);
+    assert_eq!(mask8x8::from_bits(0), mask8x8::ALL_FALSE);
+    for b in 0..=255_u8 {
+        let got = mask8x8::from_bits(u64::from_ne_bytes([b, 0, 0, 0, 0, 0, 0, b]));
+        let want = mask8x8::from_array([b != 0, false, false, false, false, false, false, b != 0]);
+        assert_eq!(got, want, "{b}");
+    }
+}
+
+#[test]
+pub fn default() {
+    assert_eq!(mask8x8::default(), mask8x8::ALL_FALSE);
+}
+
+#[test]
+pub fn hash() {
+    use std::collections::HashSet;
+    let set: HashSet<mask8x8> = (0..=255)
+        .map(|b| mask8x8::from_bitmask_le(b & 0x0f))
+        .collect();
+    assert_eq!(set.len(), 16);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+pub fn serde() {
+    let m = mask8x8::from_array([true, false, false, true, true, false, true, false]);
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(json, "[true,false,false,true,true,false,true,false]");
+    assert_eq!(serde_json::from_str::<mask8x8>(&json).unwrap(), m);
+    assert!(serde_json::from_str::<mask8x8>("[true,false]").is_err());
+}
+
+#[cfg(feature = "zerocopy")]
+#[test]
+pub fn zerocopy() {
+    use zerocopy::IntoBytes;
+
+    let m = mask8x8::from_array([true, false, false, true, true, false, true, false]);
+    assert_eq!(m.as_bytes(), [1, 0, 0, 1, 1, 0, 1, 0]);
+}
@@ -0,0 +1,989 @@
+//! Operations on 8-bit-per-pixel images and on the lower-depth formats
+//! they are commonly expanded from.
+//!
+//! Images are represented as byte slices where each row begins `stride`
+//! bytes after the previous one. The stride may be larger than the width
+//! of the image, in which case the extra bytes at the end of each row are
+//! considered to be outside of the image.
+
+use crate::vecmod::{div255_lanes_16, mul_lanes_16};
+use crate::{mask8x8, u8x8};
+
+/// A rectangle within an image, measured in pixels.
+///
+/// The position may be negative or extend beyond the edges of the image,
+/// in which case functions taking a rectangle clip it to the image bounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rect {
+    /// The horizontal position of the left edge of the rectangle.
+    pub x: isize,
+    /// The vertical position of the top edge of the rectangle.
+    pub y: isize,
+    /// The number of pixels in each row of the rectangle.
+    pub width: usize,
+    /// The number of rows in the rectangle.
+    pub height: usize,
+}
+
+impl Rect {
+    /// Returns a rectangle with the given position and size.
+    #[inline(always)]
+    pub const fn new(x: isize, y: isize, width: usize, height: usize) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the ranges of columns and rows of the rectangle that fall
+    /// within an image of the given size, each measured relative to the
+    /// rectangle's own top-left corner.
+    fn clip(
+        self,
+        width: usize,
+        height: usize,
+    ) -> (core::ops::Range<usize>, core::ops::Range<usize>) {
+        fn axis(pos: isize, len: usize, limit: usize) -> core::ops::Range<usize> {
+            let start = pos.min(0).unsigned_abs().min(len);
+            let end = (limit as isize).saturating_sub(pos).clamp(0, len as isize) as usize;
+            start..end.max(start)
+        }
+        (
+            axis(self.x, self.width, width),
+            axis(self.y, self.height, height),
+        )
+    }
+}
+
+/// Expands a one-bit-per-pixel bitmap into an 8-bit-per-pixel image,
+/// using `fg` for set bits and `bg` for unset bits.
+///
+/// Each row of `src_bits` starts `src_stride` bytes after the previous one,
+/// with the leftmost pixel in the most significant bit of the first byte.
+/// The bitmap is drawn into `dst` with its top-left corner at the position
+/// of `rect`, and `rect`'s size selects how much of the bitmap to draw.
+/// Pixels that would fall outside of `dst` are skipped, where `dst` is
+/// treated as an image `dst_stride` pixels wide.
+///
+/// ```rust
+/// # use eight_bytes::pixel::{self, Rect};
+/// let glyph = [0b0110_0000, 0b1001_0000, 0b1111_0000, 0b1001_0000];
+/// let mut fb = [0_u8; 4 * 5];
+/// pixel::blit_1bpp(&glyph, 1, &mut fb, 5, 9, 1, Rect::new(1, 0, 4, 4));
+/// assert_eq!(fb, [
+///     0, 1, 9, 9, 1,
+///     0, 9, 1, 1, 9,
+///     0, 9, 9, 9, 9,
+///     0, 9, 1, 1, 9,
+/// ]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `src_bits` is too short to contain `rect.width` pixels in each
+/// of the `rect.height` rows that are visible in `dst`.
+pub fn blit_1bpp(
+    src_bits: &[u8],
+    src_stride: usize,
+    dst: &mut [u8],
+    dst_stride: usize,
+    fg: u8,
+    bg: u8,
+    rect: Rect,
+) {
+    if dst_stride == 0 {
+        return;
+    }
+    let (cols, rows) = rect.clip(dst_stride, dst.len() / dst_stride);
+    if cols.is_empty() {
+        return;
+    }
+    let dst_x = (rect.x + cols.start as isize) as usize;
+    let first_byte = cols.start / 8;
+    let last_byte = (cols.end - 1) / 8;
+    for sy in rows {
+        let dst_y = (rect.y + sy as isize) as usize;
+        let src_row = &src_bits[sy * src_stride..][first_byte..=last_byte];
+        let dst_row = &mut dst[dst_y * dst_stride + dst_x..][..cols.len()];
+        for (i, bits) in src_row.iter().enumerate() {
+            let pixels = mask8x8::from_bitmask_be(*bits).select(fg, bg).to_array();
+            // Only the visible part of this byte's eight pixels is copied.
+            let byte_x = (first_byte + i) * 8;
+            let from = cols.start.max(byte_x);
+            let to = cols.end.min(byte_x + 8);
+            dst_row[from - cols.start..to - cols.start]
+                .copy_from_slice(&pixels[from - byte_x..to - byte_x]);
+        }
+    }
+}
+
+/// Draws an 8x8 glyph from a classic bitmap font into an 8-bit-per-pixel
+/// framebuffer with its top-left corner at `(x, y)`.
+///
+/// Each element of `glyph` is one row of the glyph, with the leftmost pixel
+/// in the most significant bit. Set bits are drawn using `fg`. Unset bits
+/// are drawn using `bg` unless `transparent_bg` is true, in which case the
+/// framebuffer pixels behind them are left unchanged. As with
+/// [`blit_1bpp`], `fb` is treated as an image `fb_stride` pixels wide and
+/// any part of the glyph outside of it is clipped.
+///
+/// ```rust
+/// # use eight_bytes::pixel;
+/// let glyph = [0b0001_1000, 0b0010_0100, 0, 0, 0, 0, 0, 0];
+/// let mut fb = [5_u8; 6 * 2];
+/// pixel::draw_glyph(&mut fb, 6, &glyph, -2, 0, 1, 0, true);
+/// assert_eq!(fb, [
+///     5, 1, 1, 5, 5, 5,
+///     1, 5, 5, 1, 5, 5,
+/// ]);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn draw_glyph(
+    fb: &mut [u8],
+    fb_stride: usize,
+    glyph: &[u8; 8],
+    x: isize,
+    y: isize,
+    fg: u8,
+    bg: u8,
+    transparent_bg: bool,
+) {
+    if !transparent_bg {
+        blit_1bpp(glyph, 1, fb, fb_stride, fg, bg, Rect::new(x, y, 8, 8));
+        return;
+    }
+    if fb_stride == 0 {
+        return;
+    }
+    let (cols, rows) = Rect::new(x, y, 8, 8).clip(fb_stride, fb.len() / fb_stride);
+    if cols.is_empty() {
+        return;
+    }
+    let dst_x = (x + cols.start as isize) as usize;
+    for gy in rows {
+        let dst_y = (y + gy as isize) as usize;
+        let dst = &mut fb[dst_y * fb_stride + dst_x..][..cols.len()];
+        let mut behind = [0_u8; 8];
+        behind[cols.clone()].copy_from_slice(dst);
+        let mask = mask8x8::from_bitmask_be(glyph[gy]);
+        let keep = mask.to_u8x8_with(0xff);
+        let pixels = (u8x8::splat(fg) & keep) | (u8x8::from_array(behind) & !keep);
+        dst.copy_from_slice(&pixels.to_array()[cols.clone()]);
+    }
+}
+
+/// Expands a row of two-bit-per-pixel indices into 8-bit-per-pixel values
+/// by looking each index up in `palette`.
+///
+/// Each byte of `src` contains four pixels, with the leftmost pixel in the
+/// two most significant bits. This function writes `dst.len()` pixels,
+/// using as many bytes of `src` as needed.
+///
+/// ```rust
+/// # use eight_bytes::pixel;
+/// let mut dst = [0_u8; 6];
+/// pixel::expand_2bpp(&[0b00_01_10_11, 0b11_10_00_00], &[10, 20, 30, 40], &mut dst);
+/// assert_eq!(dst, [10, 20, 30, 40, 40, 30]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `src` contains fewer than `dst.len()` pixels.
+pub fn expand_2bpp(src: &[u8], palette: &[u8; 4], dst: &mut [u8]) {
+    let mut table = [0_u8; 16];
+    table[..4].copy_from_slice(palette);
+    expand_packed::<4>(src, dst, |v| {
+        let mut ret = [u8x8::ZEROES; 4];
+        for (i, shift) in [6, 4, 2, 0].into_iter().enumerate() {
+            ret[i] = u8x8::new((v.n >> shift) & 0x0303030303030303).lookup_nibble(table);
+        }
+        ret
+    });
+}
+
+/// Expands a row of four-bit-per-pixel indices into 8-bit-per-pixel values
+/// by looking each index up in `palette`.
+///
+/// Each byte of `src` contains two pixels, with the leftmost pixel in the
+/// four most significant bits. This function writes `dst.len()` pixels,
+/// using as many bytes of `src` as needed.
+///
+/// ```rust
+/// # use eight_bytes::pixel;
+/// let palette = core::array::from_fn(|i| i as u8 * 16);
+/// let mut dst = [0_u8; 3];
+/// pixel::expand_4bpp(&[0x1f, 0x20], &palette, &mut dst);
+/// assert_eq!(dst, [0x10, 0xf0, 0x20]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `src` contains fewer than `dst.len()` pixels.
+pub fn expand_4bpp(src: &[u8], palette: &[u8; 16], dst: &mut [u8]) {
+    expand_packed::<2>(src, dst, |v| {
+        let hi = v.lookup_high_nibble(*palette);
+        let lo = v.lookup_nibble(*palette);
+        [hi, lo]
+    });
+}
+
+/// Shared implementation of the functions that expand packed pixels where
+/// each byte of `src` contains `PER_BYTE` pixels.
+///
+/// `expand` takes eight source bytes and returns `PER_BYTE` vectors where
+/// element `i` of vector `j` is pixel `j` from source byte `i`.
+#[inline(always)]
+fn expand_packed<const PER_BYTE: usize>(
+    src: &[u8],
+    dst: &mut [u8],
+    expand: impl Fn(u8x8) -> [u8x8; PER_BYTE],
+) {
+    let src = &src[..dst.len().div_ceil(PER_BYTE)];
+    for (src, dst) in src.chunks(8).zip(dst.chunks_mut(8 * PER_BYTE)) {
+        let mut a = [0_u8; 8];
+        a[..src.len()].copy_from_slice(src);
+        let planes = expand(u8x8::from_array(a)).map(u8x8::to_array);
+        for (i, px) in dst.iter_mut().enumerate() {
+            *px = planes[i % PER_BYTE][i / PER_BYTE];
+        }
+    }
+}
+
+/// Converts a row of pixels stored as separate bitplanes into one byte per
+/// pixel.
+///
+/// Each of the first `depth` elements of `planes` is a row of one bit per
+/// pixel with the leftmost pixel in the most significant bit of the first
+/// byte, and contributes the bit of each pixel value whose position matches
+/// its index in `planes`. Bits at positions `depth` and above are zero.
+/// This function writes `dst.len()` pixels, using as many bytes of each
+/// plane as needed.
+///
+/// ```rust
+/// # use eight_bytes::pixel;
+/// let plane0: &[u8] = &[0b1010_0000];
+/// let plane1: &[u8] = &[0b0110_0000];
+/// let mut dst = [0_u8; 4];
+/// pixel::planes_to_chunky(&[plane0, plane1], 2, &mut dst);
+/// assert_eq!(dst, [1, 2, 3, 0]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `depth` is greater than eight, if `planes` has fewer than
+/// `depth` elements, or if any of those planes contains fewer than
+/// `dst.len()` pixels.
+pub fn planes_to_chunky(planes: &[&[u8]], depth: usize, dst: &mut [u8]) {
+    assert!(depth <= 8, "depth must be at most eight");
+    let planes = &planes[..depth];
+    for (k, dst) in dst.chunks_mut(8).enumerate() {
+        let mut a = [0_u8; 8];
+        for (b, plane) in a.iter_mut().zip(planes) {
+            *b = plane[k];
+        }
+        // After transposing, element i holds the bits of the pixel at
+        // position 7-i, because the leftmost pixel is the most significant.
+        let t = u8x8::from_array(a).transpose_bits();
+        let px = u8x8::new(t.n.swap_bytes()).to_array();
+        dst.copy_from_slice(&px[..dst.len()]);
+    }
+}
+
+/// Converts a row of one byte per pixel into separate bitplanes.
+///
+/// This is the inverse of [`planes_to_chunky`]: the first `depth` elements
+/// of `planes` each receive the bit at the corresponding position of each
+/// pixel in `src`, packed with the leftmost pixel in the most significant
+/// bit. Any unused bits in the final byte of each plane are set to zero.
+///
+/// # Panics
+///
+/// Panics if `depth` is greater than eight, if `planes` has fewer than
+/// `depth` elements, or if any of those planes is too short to hold
+/// `src.len()` pixels.
+pub fn chunky_to_planes(src: &[u8], depth: usize, planes: &mut [&mut [u8]]) {
+    assert!(depth <= 8, "depth must be at most eight");
+    let planes = &mut planes[..depth];
+    for (k, src) in src.chunks(8).enumerate() {
+        let mut a = [0_u8; 8];
+        a[..src.len()].copy_from_slice(src);
+        let v = u8x8::from_array(a);
+        let t = u8x8::new(v.n.swap_bytes()).transpose_bits().to_array();
+        for (plane, b) in planes.iter_mut().zip(t) {
+            plane[k] = b;
+        }
+    }
+}
+
+/// Copies the pixels of `src` into `dst`, except for those equal to `key`,
+/// which leave the corresponding destination pixels unchanged.
+///
+/// This is the usual way to draw sprites with a transparent color.
+///
+/// ```rust
+/// # use eight_bytes::pixel;
+/// let mut dst = [1, 2, 3, 4, 5];
+/// pixel::blit_keyed(&mut dst, &[0, 9, 0, 9, 9], 0);
+/// assert_eq!(dst, [1, 9, 3, 9, 9]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the two slices have different lengths.
+pub fn blit_keyed(dst: &mut [u8], src: &[u8], key: u8) {
+    let key = u8x8::splat(key);
+    combine_rows(dst, src, |d, s| {
+        let opaque = s.equals(key).not().to_u8x8_with(0xff);
+        (s & opaque) | (d & !opaque)
+    });
+}
+
+/// Blends the pixels of `src` over those of `dst` with constant opacity
+/// `alpha`, computing `(src * alpha + dst * (255 - alpha)) / 255` for each
+/// pair of pixels, rounded down, using [`u8x8::lerp`].
+///
+/// An `alpha` of 255 copies `src`, while zero leaves `dst` unchanged.
+///
+/// ```rust
+/// # use eight_bytes::pixel;
+/// let mut dst = [0, 100, 255];
+/// pixel::blend(&mut dst, &[255, 200, 0], 128);
+/// assert_eq!(dst, [128, 150, 127]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the two slices have different lengths.
+pub fn blend(dst: &mut [u8], src: &[u8], alpha: u8) {
+    combine_rows(dst, src, |d, s| d.lerp(s, alpha));
+}
+
+/// Blends the pixels of `src` over those of `dst` using the corresponding
+/// element of `alpha` as the opacity of each pixel, with the same formula
+/// as [`blend`].
+///
+/// ```rust
+/// # use eight_bytes::pixel;
+/// let mut dst = [0, 100, 255];
+/// pixel::blend_per_pixel(&mut dst, &[255, 200, 0], &[255, 0, 51]);
+/// assert_eq!(dst, [255, 100, 204]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the three slices do not all have the same length.
+pub fn blend_per_pixel(dst: &mut [u8], src: &[u8], alpha: &[u8]) {
+    assert_eq!(
+        dst.len(),
+        alpha.len(),
+        "alpha and destination lengths differ"
+    );
+    let mut alpha = alpha.chunks(8);
+    combine_rows(dst, src, |d, s| {
+        let chunk = alpha.next().unwrap();
+        let mut a = [0_u8; 8];
+        a[..chunk.len()].copy_from_slice(chunk);
+        let a = u8x8::from_array(a);
+        let (d_even, d_odd) = split_wide(d);
+        let (s_even, s_odd) = split_wide(s);
+        let (a_even, a_odd) = split_wide(a);
+        let (i_even, i_odd) = split_wide(!a);
+        let even = div255_lanes_16(mul_lanes_16(s_even, a_even) + mul_lanes_16(d_even, i_even));
+        let odd = div255_lanes_16(mul_lanes_16(s_odd, a_odd) + mul_lanes_16(d_odd, i_odd));
+        join_wide(even, odd)
+    });
+}
+
+/// Adds the pixels of `src` to those of `dst`, saturating at 255.
+///
+/// This is additive compositing, as used for glow and lighten effects.
+///
+/// ```rust
+/// # use eight_bytes::pixel;
+/// let mut dst = [10, 200, 255];
+/// pixel::add_saturating(&mut dst, &[5, 100, 1]);
+/// assert_eq!(dst, [15, 255, 255]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the two slices have different lengths.
+pub fn add_saturating(dst: &mut [u8], src: &[u8]) {
+    combine_rows(dst, src, u8x8::saturating_add);
+}
+
+/// Subtracts the pixels of `src` from those of `dst`, saturating at zero.
+///
+/// This is subtractive compositing, as used for shadow and darken effects.
+///
+/// ```rust
+/// # use eight_bytes::pixel;
+/// let mut dst = [10, 200, 0];
+/// pixel::sub_saturating(&mut dst, &[5, 201, 1]);
+/// assert_eq!(dst, [5, 0, 0]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the two slices have different lengths.
+pub fn sub_saturating(dst: &mut [u8], src: &[u8]) {
+    combine_rows(dst, src, u8x8::saturating_sub);
+}
+
+/// Sets each pixel of `dst` to the mean of the corresponding pixels of `a`
+/// and `b`, rounding halves upward.
+///
+/// This is a 50% cross-fade between the two rows, and is also useful for
+/// deflickering interlaced images by averaging adjacent lines.
+///
+/// ```rust
+/// # use eight_bytes::pixel;
+/// let mut dst = [0; 3];
+/// pixel::average_rows(&mut dst, &[0, 10, 255], &[255, 20, 254]);
+/// assert_eq!(dst, [128, 15, 255]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the three slices do not all have the same length.
+pub fn average_rows(dst: &mut [u8], a: &[u8], b: &[u8]) {
+    dst.copy_from_slice(a);
+    combine_rows(dst, b, u8x8::rounding_mean);
+}
+
+/// Adjusts the brightness and contrast of each pixel in `dst`.
+///
+/// `contrast` is a scale factor in fixed point with seven fractional bits,
+/// so that 128 leaves the contrast unchanged, zero reduces every pixel to
+/// mid-gray, and larger values stretch pixel values away from the middle.
+/// `brightness` is then added, saturating at zero and 255. Overall, each
+/// pixel `x` becomes `128 + brightness + floor((x - 128) * contrast / 128)`,
+/// clamped to the range of `u8`.
+///
+/// ```rust
+/// # use eight_bytes::pixel;
+/// let mut px = [0, 100, 128, 200, 255];
+/// pixel::adjust(&mut px, 10, 192);
+/// assert_eq!(px, [0, 96, 138, 246, 255]);
+/// ```
+pub fn adjust(dst: &mut [u8], brightness: i16, contrast: u8) {
+    const ONES: u64 = 0x0001000100010001;
+    let c = contrast as u64;
+    // Expanding the formula gives `floor(x * c / 128) + k`. We add a bias
+    // of 512 so that intermediate lane values are never negative, and clamp
+    // k to the range beyond which all results saturate in the same way.
+    let k = (128 + brightness as i32 - contrast as i32).clamp(-512, 256);
+    let biased_k = (k + 512) as u64 * ONES;
+    map_row(dst, |v| {
+        let (even, odd) = split_wide(v);
+        let adjust = |x: u64| {
+            let scaled = ((x * c) >> 7) & 0x01ff01ff01ff01ff;
+            clamp_biased_wide(scaled + biased_k)
+        };
+        join_wide(adjust(even), adjust(odd))
+    });
+}
+
+/// Inverts each pixel in `dst`, replacing each value `x` with `255 - x`.
+pub fn invert(dst: &mut [u8]) {
+    map_row(dst, u8x8::complement);
+}
+
+/// Converts a row of 8-bit pixels into a one-bit-per-pixel bitmap where
+/// pixels greater than `cutoff` are represented by set bits.
+///
+/// The bitmap uses the same layout as the source of [`blit_1bpp`], with the
+/// leftmost pixel in the most significant bit of the first byte. Any unused
+/// bits in the final byte are set to zero.
+///
+/// ```rust
+/// # use eight_bytes::pixel;
+/// let mut bits = [0_u8; 2];
+/// pixel::threshold(&[0, 200, 127, 128, 255, 10, 90, 250, 129], 127, &mut bits);
+/// assert_eq!(bits, [0b0101_1001, 0b1000_0000]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `dst_bits` is too short to hold `src.len()` bits.
+pub fn threshold(src: &[u8], cutoff: u8, dst_bits: &mut [u8]) {
+    let cutoff = u8x8::splat(cutoff);
+    let dst_bits = &mut dst_bits[..src.len().div_ceil(8)];
+    for (src, bits) in src.chunks(8).zip(dst_bits) {
+        let mut a = [0_u8; 8];
+        a[..src.len()].copy_from_slice(src);
+        // Padding elements are zero, so they are never above the cutoff.
+        *bits = u8x8::from_array(a).greater_than(cutoff).to_bitmask_be();
+    }
+}
+
+/// A matrix of thresholds for ordered dithering with [`dither_ordered`].
+///
+/// The matrix is always stored as 8x8, with smaller matrices repeated to
+/// fill it, so that each row of thresholds is a single vector.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BayerMatrix {
+    rows: [u8x8; 8],
+}
+
+impl BayerMatrix {
+    /// The 2x2 Bayer matrix, giving five levels of gray.
+    pub const BAYER_2X2: Self = Self::bayer(2);
+
+    /// The 4x4 Bayer matrix, giving seventeen levels of gray.
+    pub const BAYER_4X4: Self = Self::bayer(4);
+
+    /// The 8x8 Bayer matrix, giving sixty-five levels of gray.
+    pub const BAYER_8X8: Self = Self::bayer(8);
+
+    /// Returns a matrix with the given thresholds, where `rows[y][x]` is the
+    /// threshold for pixels whose position modulo eight is `(x, y)`.
+    ///
+    /// A pixel becomes set only if its value is greater than its threshold.
+    #[inline(always)]
+    pub const fn from_rows(rows: [[u8; 8]; 8]) -> Self {
+        let mut ret = [u8x8::ZEROES; 8];
+        let mut y = 0;
+        while y < 8 {
+            ret[y] = u8x8::from_array(rows[y]);
+            y += 1;
+        }
+        Self { rows: ret }
+    }
+
+    /// Builds the Bayer matrix of the given size, which must be a power of
+    /// two no greater than eight.
+    const fn bayer(size: usize) -> Self {
+        // Each doubling of the size replaces every index m with the 2x2
+        // block [[4m, 4m + 2], [4m + 3, 4m + 1]].
+        let mut index = [[0_u8; 8]; 8];
+        let mut n = 1;
+        while n < size {
+            let mut next = [[0_u8; 8]; 8];
+            let mut y = 0;
+            while y < n * 2 {
+                let mut x = 0;
+                while x < n * 2 {
+                    let offset = match (y >= n, x >= n) {
+                        (false, false) => 0,
+                        (false, true) => 2,
+                        (true, false) => 3,
+                        (true, true) => 1,
+                    };
+                    next[y][x] = index[y % n][x % n] * 4 + offset;
+                    x += 1;
+                }
+                y += 1;
+            }
+            index = next;
+            n *= 2;
+        }
+        // Each index is scaled so its threshold is at the center of its
+        // share of the 0..=255 range.
+        let mut rows = [[0_u8; 8]; 8];
+        let mut y = 0;
+        while y < 8 {
+            let mut x = 0;
+            while x < 8 {
+                let m = index[y % size][x % size] as usize;
+                rows[y][x] = ((2 * m + 1) * 128 / (size * size)) as u8;
+                x += 1;
+            }
+            y += 1;
+        }
+        Self::from_rows(rows)
+    }
+}
+
+/// Converts row `y` of an 8-bit grayscale image into a one-bit-per-pixel
+/// bitmap using ordered dithering, with the bitmap layout of [`threshold`].
+///
+/// Each pixel is compared with the threshold at its position in `matrix`,
+/// which repeats every eight pixels both horizontally and vertically, and
+/// the row is assumed to start at the left edge of the image.
+///
+/// ```rust
+/// # use eight_bytes::pixel::{self, BayerMatrix};
+/// let gray = [128_u8; 8];
+/// let mut bits = [[0_u8]; 2];
+/// pixel::dither_ordered(&gray, &mut bits[0], &BayerMatrix::BAYER_2X2, 0);
+/// pixel::dither_ordered(&gray, &mut bits[1], &BayerMatrix::BAYER_2X2, 1);
+/// assert_eq!(bits, [[0b1010_1010], [0b0101_0101]]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `dst_bits` is too short to hold `src.len()` bits.
+pub fn dither_ordered(src: &[u8], dst_bits: &mut [u8], matrix: &BayerMatrix, y: usize) {
+    let thresholds = matrix.rows[y % 8];
+    let dst_bits = &mut dst_bits[..src.len().div_ceil(8)];
+    for (src, bits) in src.chunks(8).zip(dst_bits) {
+        let mut a = [0_u8; 8];
+        a[..src.len()].copy_from_slice(src);
+        let set = u8x8::from_array(a).greater_than(thresholds).to_bitmask_be();
+        // Padding elements might exceed a zero threshold in a custom matrix.
+        *bits = set & !(0xff_u8.checked_shr(src.len() as u32).unwrap_or(0));
+    }
+}
+
+/// Converts a row of pixels with interleaved 8-bit red, green, and blue
+/// channels into 8-bit grayscale.
+///
+/// Each gray value is `(77 * r + 151 * g + 28 * b) / 256`, rounded down,
+/// which approximates the ITU-R BT.601 luma weights. This function writes
+/// `dst.len()` pixels, using three bytes of `src_rgb` for each.
+///
+/// ```rust
+/// # use eight_bytes::pixel;
+/// let mut gray = [0_u8; 3];
+/// pixel::rgb_to_gray(&[255, 0, 0, 255, 255, 255, 10, 200, 30], &mut gray);
+/// assert_eq!(gray, [76, 255, 124]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `src_rgb` is shorter than three times the length of `dst`.
+pub fn rgb_to_gray(src_rgb: &[u8], dst: &mut [u8]) {
+    let src_rgb = &src_rgb[..dst.len() * 3];
+    for (src, dst) in src_rgb.chunks(24).zip(dst.chunks_mut(8)) {
+        let mut channels = [[0_u8; 8]; 3];
+        for (i, px) in src.chunks_exact(3).enumerate() {
+            for c in 0..3 {
+                channels[c][i] = px[c];
+            }
+        }
+        let [r, g, b] = channels.map(|c| split_wide(u8x8::from_array(c)));
+        let gray = |r: u64, g: u64, b: u64| (r * 77 + g * 151 + b * 28) >> 8;
+        let v = join_wide(gray(r.0, g.0, b.0), gray(r.1, g.1, b.1));
+        dst.copy_from_slice(&v.to_array()[..dst.len()]);
+    }
+}
+
+/// Packs separate rows of 8-bit red, green, and blue channels into RGB565
+/// pixels, the format used by most small TFT displays.
+///
+/// Each pixel occupies two bytes of `dst` in big-endian order, with red in
+/// the five most significant bits and blue in the five least significant
+/// bits. The low bits of each channel that don't fit are discarded.
+///
+/// ```rust
+/// # use eight_bytes::pixel;
+/// let mut dst = [0_u8; 4];
+/// pixel::pack_rgb565(&[0xff, 0x12], &[0x80, 0x34], &[0x00, 0x56], &mut dst);
+/// assert_eq!(dst, [0xfc, 0x00, 0x11, 0xaa]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the three channel slices have different lengths, or if `dst`
+/// is shorter than twice their length.
+pub fn pack_rgb565(r: &[u8], g: &[u8], b: &[u8], dst: &mut [u8]) {
+    assert!(
+        r.len() == g.len() && g.len() == b.len(),
+        "channel lengths differ"
+    );
+    let dst = &mut dst[..r.len() * 2];
+    let chunks = r.chunks(8).zip(g.chunks(8)).zip(b.chunks(8));
+    for (((r, g), b), dst) in chunks.zip(dst.chunks_mut(16)) {
+        let [r, g, b] = [r, g, b].map(|c| {
+            let mut a = [0_u8; 8];
+            a[..c.len()].copy_from_slice(c);
+            u8x8::from_array(a)
+        });
+        let hi = (r & u8x8::splat(0xf8)) | g.shr(5);
+        let lo = (g.shl(3) & u8x8::splat(0xe0)) | b.shr(3);
+        interleave_into(hi, lo, dst);
+    }
+}
+
+/// Unpacks big-endian RGB565 pixels into separate rows of 8-bit red, green,
+/// and blue channels.
+///
+/// This is the inverse of [`pack_rgb565`]. The high bits of each channel
+/// are repeated into the low bits, so that the full-intensity value of each
+/// channel becomes 255.
+///
+/// # Panics
+///
+/// Panics if the three channel slices have different lengths, or if `src`
+/// is shorter than twice their length.
+pub fn unpack_rgb565(src: &[u8], r: &mut [u8], g: &mut [u8], b: &mut [u8]) {
+    assert!(
+        r.len() == g.len() && g.len() == b.len(),
+        "channel lengths differ"
+    );
+    let src = &src[..r.len() * 2];
+    let chunks = r.chunks_mut(8).zip(g.chunks_mut(8)).zip(b.chunks_mut(8));
+    for (((r, g), b), src) in chunks.zip(src.chunks(16)) {
+        let (mut hi, mut lo) = ([0_u8; 8], [0_u8; 8]);
+        for (i, px) in src.chunks_exact(2).enumerate() {
+            hi[i] = px[0];
+            lo[i] = px[1];
+        }
+        let (hi, lo) = (u8x8::from_array(hi), u8x8::from_array(lo));
+        let r5 = hi & u8x8::splat(0xf8);
+        let g6 = (hi & u8x8::splat(0x07)).shl(3) | lo.shr(5);
+        let b5 = lo & u8x8::splat(0x1f);
+        let r8 = r5 | r5.shr(5);
+        let g8 = g6.shl(2) | g6.shr(4);
+        let b8 = b5.shl(3) | b5.shr(2);
+        for (dst, v) in [(r, r8), (g, g8), (b, b8)] {
+            dst.copy_from_slice(&v.to_array()[..dst.len()]);
+        }
+    }
+}
+
+/// Halves the width of a row of pixels by setting each pixel of `dst` to
+/// the mean of a pair of adjacent pixels from `src`, rounding halves upward.
+///
+/// This function writes `dst.len()` pixels, using two pixels of `src` for
+/// each. Averaging the results of two rows in the same way with
+/// [`average_rows`] gives a 2x2 box downscale, as used for thumbnails and
+/// mipmaps.
+///
+/// ```rust
+/// # use eight_bytes::pixel;
+/// let mut dst = [0_u8; 3];
+/// pixel::downscale2x(&[0, 255, 10, 20, 7, 8], &mut dst);
+/// assert_eq!(dst, [128, 15, 8]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `src` is shorter than twice the length of `dst`.
+pub fn downscale2x(src: &[u8], dst: &mut [u8]) {
+    let src = &src[..dst.len() * 2];
+    for (src, dst) in src.chunks(16).zip(dst.chunks_mut(8)) {
+        let (mut even, mut odd) = ([0_u8; 8], [0_u8; 8]);
+        for (i, pair) in src.chunks_exact(2).enumerate() {
+            even[i] = pair[0];
+            odd[i] = pair[1];
+        }
+        let v = u8x8::from_array(even).rounding_mean(u8x8::from_array(odd));
+        dst.copy_from_slice(&v.to_array()[..dst.len()]);
+    }
+}
+
+/// Smooths a row of pixels by setting each pixel of `dst` to the mean of
+/// the corresponding pixel of `src` and its two neighbors, rounded to the
+/// nearest integer.
+///
+/// Pixels beyond either end of the row are treated as copies of the
+/// pixel at that end.
+///
+/// ```rust
+/// # use eight_bytes::pixel;
+/// let mut dst = [0_u8; 5];
+/// pixel::box_blur3(&[0, 0, 255, 0, 10], &mut dst);
+/// assert_eq!(dst, [0, 85, 85, 88, 7]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the two slices have different lengths.
+pub fn box_blur3(src: &[u8], dst: &mut [u8]) {
+    const ONES: u64 = 0x0001000100010001;
+    assert_eq!(
+        dst.len(),
+        src.len(),
+        "source and destination lengths differ"
+    );
+    let (Some(&first), Some(&last)) = (src.first(), src.last()) else {
+        return;
+    };
+    let mut prev = first;
+    for (k, (src_chunk, dst)) in src.chunks(8).zip(dst.chunks_mut(8)).enumerate() {
+        // A short final chunk is padded with copies of the last pixel, so
+        // that it becomes the right neighbor of itself.
+        let mut a = [last; 8];
+        a[..src_chunk.len()].copy_from_slice(src_chunk);
+        let next = src.get(k * 8 + 8).copied().unwrap_or(last);
+        let cur = u8x8::from_array(a);
+        let left = split_wide(cur.shift_elements_up(prev));
+        let right = split_wide(cur.shift_elements_down(next));
+        let cur_wide = split_wide(cur);
+        let even = div3_wide(left.0 + cur_wide.0 + right.0 + ONES);
+        let odd = div3_wide(left.1 + cur_wide.1 + right.1 + ONES);
+        dst.copy_from_slice(&join_wide(even, odd).to_array()[..dst.len()]);
+        prev = a[7];
+    }
+}
+
+/// Sets each pixel of `dst` to the absolute difference between the
+/// corresponding pixel of `src` and its right neighbor, which highlights
+/// vertical edges.
+///
+/// The last pixel is treated as its own neighbor, and so is always zero.
+///
+/// ```rust
+/// # use eight_bytes::pixel;
+/// let mut dst = [0_u8; 5];
+/// pixel::gradient_x(&[10, 10, 200, 190, 190], &mut dst);
+/// assert_eq!(dst, [0, 190, 10, 0, 0]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the two slices have different lengths.
+pub fn gradient_x(src: &[u8], dst: &mut [u8]) {
+    assert_eq!(
+        dst.len(),
+        src.len(),
+        "source and destination lengths differ"
+    );
+    let Some(&last) = src.last() else {
+        return;
+    };
+    for (k, (src_chunk, dst)) in src.chunks(8).zip(dst.chunks_mut(8)).enumerate() {
+        let mut a = [last; 8];
+        a[..src_chunk.len()].copy_from_slice(src_chunk);
+        let next = src.get(k * 8 + 8).copied().unwrap_or(last);
+        let cur = u8x8::from_array(a);
+        let diff = cur.abs_difference(cur.shift_elements_down(next));
+        dst.copy_from_slice(&diff.to_array()[..dst.len()]);
+    }
+}
+
+/// Doubles the width of a row of pixels by repeating each pixel of `src`
+/// twice in `dst`, for nearest-neighbor upscaling.
+///
+/// This function writes `dst.len()` pixels, using one pixel of `src` for
+/// every two.
+///
+/// ```rust
+/// # use eight_bytes::pixel;
+/// let mut dst = [0_u8; 5];
+/// pixel::scale2x_row(&[1, 2, 3], &mut dst);
+/// assert_eq!(dst, [1, 1, 2, 2, 3]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `src` has fewer than half as many pixels as `dst`, rounded up.
+pub fn scale2x_row(src: &[u8], dst: &mut [u8]) {
+    let src = &src[..dst.len().div_ceil(2)];
+    for (src, dst) in src.chunks(8).zip(dst.chunks_mut(16)) {
+        let mut a = [0_u8; 8];
+        a[..src.len()].copy_from_slice(src);
+        let v = u8x8::from_array(a);
+        interleave_into(v, v, dst);
+    }
+}
+
+/// Mask selecting the low byte of each 16-bit lane of a `u64`.
+const LOW_BYTES: u64 = 0x00ff00ff00ff00ff;
+
+/// Splits the elements of a vector into two `u64` values containing four
+/// 16-bit lanes each, holding the even and odd elements respectively.
+///
+/// The lanes are in the native representation, so arithmetic on them is
+/// consistent only as long as it does not depend on lane order.
+#[inline(always)]
+const fn split_wide(v: u8x8) -> (u64, u64) {
+    (v.n & LOW_BYTES, (v.n >> 8) & LOW_BYTES)
+}
+
+/// The inverse of [`split_wide`], taking the low byte of each lane.
+#[inline(always)]
+const fn join_wide(even: u64, odd: u64) -> u8x8 {
+    u8x8::new((even & LOW_BYTES) | ((odd & LOW_BYTES) << 8))
+}
+
+/// Writes the elements of `a` and `b` alternately into `dst`, starting with
+/// the first element of `a`, for as many elements as fit in `dst`.
+#[inline(always)]
+fn interleave_into(a: u8x8, b: u8x8, dst: &mut [u8]) {
+    let (a, b) = (a.to_array(), b.to_array());
+    for (i, px) in dst.iter_mut().enumerate() {
+        *px = if i % 2 == 0 { a[i / 2] } else { b[i / 2] };
+    }
+}
+
+/// Divides each 16-bit lane by three, rounding down, for lane values up to
+/// 770.
+#[inline(always)]
+const fn div3_wide(x: u64) -> u64 {
+    const ONES: u64 = 0x0001000100010001;
+    // Multiplying by 85/256 slightly underestimates a third, by at most two
+    // for values in range, and then the remainder tells us how much to add
+    // back: multiplying by 11/32 is an exact division by three for the
+    // remainders up to eight that can result.
+    let q = ((x * 85) >> 8) & LOW_BYTES;
+    let r = x - q * 3;
+    q + (((r * 11) >> 5) & (ONES * 3))
+}
+
+/// Subtracts 512 from each 16-bit lane and clamps the result to the range
+/// of `u8`, for lane values less than 2048.
+#[inline(always)]
+const fn clamp_biased_wide(t: u64) -> u64 {
+    const ONES: u64 = 0x0001000100010001;
+    const HIGH_BITS: u64 = 0x8000800080008000;
+    // Lanes that were at least 512 now have their high bit set, and the
+    // remaining bits hold the value minus 512.
+    let a = t + (0x8000 - 512) * ONES;
+    let non_negative = ((a & HIGH_BITS) >> 15) * 0xffff;
+    let u = a & !HIGH_BITS & non_negative;
+    // Lanes that were greater than 255 now have their high bit set.
+    let over = (((u + (0x8000 - 256) * ONES) & HIGH_BITS) >> 15) * 0xffff;
+    (u & !over) | (LOW_BYTES & over)
+}
+
+/// Replaces each group of eight pixels in `dst` with the result of `f`
+/// applied to it.
+///
+/// If the length is not a multiple of eight then the final call to `f`
+/// receives a zero-padded vector, and the padding elements of its result
+/// are discarded.
+#[inline(always)]
+fn map_row(dst: &mut [u8], mut f: impl FnMut(u8x8) -> u8x8) {
+    let mut chunks = dst.chunks_exact_mut(8);
+    for d in &mut chunks {
+        let v = u8x8::from_array((*d).try_into().unwrap());
+        d.copy_from_slice(&f(v).to_array());
+    }
+    let d = chunks.into_remainder();
+    if !d.is_empty() {
+        let mut a = [0_u8; 8];
+        a[..d.len()].copy_from_slice(d);
+        let r = f(u8x8::from_array(a)).to_array();
+        d.copy_from_slice(&r[..d.len()]);
+    }
+}
+
+/// Replaces each group of eight pixels in `dst` with the result of `f`
+/// applied to it and the corresponding group of pixels from `src`.
+///
+/// If the length is not a multiple of eight then the final call to `f`
+/// receives zero-padded vectors, and the padding elements of its result
+/// are discarded.
+///
+/// # Panics
+///
+/// Panics if the two slices have different lengths.
+#[inline(always)]
+fn combine_rows(dst: &mut [u8], src: &[u8], mut f: impl FnMut(u8x8, u8x8) -> u8x8) {
+    assert_eq!(
+        dst.len(),
+        src.len(),
+        "source and destination lengths differ"
+    );
+    let mut dst_chunks = dst.chunks_exact_mut(8);
+    let mut src_chunks = src.chunks_exact(8);
+    for (d, s) in (&mut dst_chunks).zip(&mut src_chunks) {
+        let d8 = u8x8::from_array((*d).try_into().unwrap());
+        let s8 = u8x8::from_array(s.try_into().unwrap());
+        d.copy_from_slice(&f(d8, s8).to_array());
+    }
+    let d = dst_chunks.into_remainder();
+    if !d.is_empty() {
+        let s = src_chunks.remainder();
+        let (mut d8, mut s8) = ([0_u8; 8], [0_u8; 8]);
+        d8[..d.len()].copy_from_slice(d);
+        s8[..s.len()].copy_from_slice(s);
+        let r = f(u8x8::from_array(d8), u8x8::from_array(s8)).to_array();
+        d.copy_from_slice(&r[..d.len()]);
+    }
+}
@@ -0,0 +1,416 @@
+use super::*;
+use pixel::Rect;
+
+#[test]
+pub fn blit_1bpp() {
+    // A 10x2 bitmap, so that each row spans two bytes.
+    let src = [0b1100_1010, 0b0100_0000, 0b0011_0101, 0b1000_0000];
+    let mut dst = [0_u8; 12 * 3];
+    pixel::blit_1bpp(&src, 2, &mut dst, 12, 7, 2, Rect::new(1, 1, 10, 2));
+    #[rustfmt::skip]
+    let want = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 7, 7, 2, 2, 7, 2, 7, 2, 2, 7, 0,
+        0, 2, 2, 7, 7, 2, 7, 2, 7, 7, 2, 0,
+    ];
+    assert_eq!(dst, want);
+}
+
+#[test]
+pub fn blit_1bpp_clipped() {
+    let src = [0b1100_1010, 0b0100_0000, 0b0011_0101, 0b1000_0000];
+    let mut dst = [0_u8; 6 * 2];
+    // Shifted left and up past the edges, and right past the far edge.
+    pixel::blit_1bpp(&src, 2, &mut dst, 6, 7, 2, Rect::new(-3, -1, 10, 2));
+    #[rustfmt::skip]
+    let want = [
+        7, 2, 7, 2, 7, 7,
+        0, 0, 0, 0, 0, 0,
+    ];
+    assert_eq!(dst, want);
+
+    let mut dst = [0_u8; 6 * 2];
+    pixel::blit_1bpp(&src, 2, &mut dst, 6, 7, 2, Rect::new(4, 1, 10, 2));
+    #[rustfmt::skip]
+    let want = [
+        0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 7, 7,
+    ];
+    assert_eq!(dst, want);
+
+    // Entirely outside of the destination.
+    let mut dst = [0_u8; 6 * 2];
+    pixel::blit_1bpp(&src, 2, &mut dst, 6, 7, 2, Rect::new(6, 0, 10, 2));
+    pixel::blit_1bpp(&src, 2, &mut dst, 6, 7, 2, Rect::new(-10, 0, 10, 2));
+    assert_eq!(dst, [0; 12]);
+}
+
+#[test]
+pub fn expand_2bpp() {
+    let src: [u8; 11] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x9d));
+    let palette = [0xa0, 0xb1, 0xc2, 0xd3];
+    for len in [0, 1, 5, 32, 41, 44] {
+        let mut got = [0_u8; 44];
+        pixel::expand_2bpp(&src, &palette, &mut got[..len]);
+        let mut want = [0_u8; 44];
+        for (i, px) in want[..len].iter_mut().enumerate() {
+            let idx = (src[i / 4] >> (6 - (i % 4) * 2)) & 0b11;
+            *px = palette[idx as usize];
+        }
+        assert_eq!(got, want, "length {len}");
+    }
+}
+
+#[test]
+pub fn expand_4bpp() {
+    let src: [u8; 11] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x9d));
+    let palette: [u8; 16] = core::array::from_fn(|i| (i as u8) * 3 + 100);
+    for len in [0, 1, 5, 16, 21, 22] {
+        let mut got = [0_u8; 22];
+        pixel::expand_4bpp(&src, &palette, &mut got[..len]);
+        let mut want = [0_u8; 22];
+        for (i, px) in want[..len].iter_mut().enumerate() {
+            let idx = (src[i / 2] >> (4 - (i % 2) * 4)) & 0xf;
+            *px = palette[idx as usize];
+        }
+        assert_eq!(got, want, "length {len}");
+    }
+}
+
+#[test]
+pub fn planes_to_chunky() {
+    let p0: [u8; 3] = [0b1010_1010, 0b1111_0000, 0b1000_0000];
+    let p1: [u8; 3] = [0b1100_1100, 0b0000_1111, 0b1000_0000];
+    let p2: [u8; 3] = [0b1111_0000, 0b0101_0101, 0b0000_0000];
+    let mut got = [0xff_u8; 18];
+    pixel::planes_to_chunky(&[&p0, &p1, &p2], 3, &mut got[..17]);
+    #[rustfmt::skip]
+    let want = [
+        7, 6, 5, 4, 3, 2, 1, 0,
+        1, 5, 1, 5, 2, 6, 2, 6,
+        3, 0xff,
+    ];
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn chunky_to_planes() {
+    let src: [u8; 21] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x35));
+    let mut planes = [[0xaa_u8; 3]; 8];
+    let mut plane_refs: Vec<&mut [u8]> = planes.iter_mut().map(|p| &mut p[..]).collect();
+    pixel::chunky_to_planes(&src, 8, &mut plane_refs);
+
+    let plane_refs: Vec<&[u8]> = planes.iter().map(|p| &p[..]).collect();
+    let mut got = [0_u8; 21];
+    pixel::planes_to_chunky(&plane_refs, 8, &mut got);
+    assert_eq!(got, src);
+    // The three unused bits at the end of each plane are cleared.
+    for plane in planes {
+        assert_eq!(plane[2] & 0b111, 0);
+    }
+}
+
+#[test]
+pub fn blit_keyed() {
+    let src: [u8; 19] = core::array::from_fn(|i| if i % 3 == 0 { 0xee } else { i as u8 });
+    let mut got = [0x55_u8; 19];
+    pixel::blit_keyed(&mut got, &src, 0xee);
+    let want: [u8; 19] = core::array::from_fn(|i| if i % 3 == 0 { 0x55 } else { i as u8 });
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn blend() {
+    let src: [u8; 19] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x3b));
+    let orig: [u8; 19] = core::array::from_fn(|i| (i as u8).wrapping_mul(0xc5) ^ 0x5a);
+    for alpha in [0, 1, 127, 128, 200, 254, 255] {
+        let mut got = orig;
+        pixel::blend(&mut got, &src, alpha);
+        let a = alpha as u32;
+        let want: [u8; 19] = core::array::from_fn(|i| {
+            ((src[i] as u32 * a + orig[i] as u32 * (255 - a)) / 255) as u8
+        });
+        assert_eq!(got, want, "alpha {alpha}");
+    }
+}
+
+#[test]
+pub fn blend_per_pixel() {
+    let src: [u8; 19] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x3b));
+    let alpha: [u8; 19] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x71));
+    let mut got: [u8; 19] = core::array::from_fn(|i| (i as u8).wrapping_mul(0xc5) ^ 0x5a);
+    let want: [u8; 19] = core::array::from_fn(|i| {
+        let (s, d, a) = (src[i] as u32, got[i] as u32, alpha[i] as u32);
+        ((s * a + d * (255 - a)) / 255) as u8
+    });
+    pixel::blend_per_pixel(&mut got, &src, &alpha);
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn add_saturating() {
+    let src: [u8; 19] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x3b));
+    let orig: [u8; 19] = core::array::from_fn(|i| (i as u8).wrapping_mul(0xc5) ^ 0x5a);
+    let mut got = orig;
+    pixel::add_saturating(&mut got, &src);
+    let want: [u8; 19] = core::array::from_fn(|i| orig[i].saturating_add(src[i]));
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn sub_saturating() {
+    let src: [u8; 19] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x3b));
+    let orig: [u8; 19] = core::array::from_fn(|i| (i as u8).wrapping_mul(0xc5) ^ 0x5a);
+    let mut got = orig;
+    pixel::sub_saturating(&mut got, &src);
+    let want: [u8; 19] = core::array::from_fn(|i| orig[i].saturating_sub(src[i]));
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn average_rows() {
+    let a: [u8; 19] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x3b));
+    let b: [u8; 19] = core::array::from_fn(|i| (i as u8).wrapping_mul(0xc5) ^ 0x5a);
+    let mut got = [0_u8; 19];
+    pixel::average_rows(&mut got, &a, &b);
+    let want: [u8; 19] = core::array::from_fn(|i| (a[i] as u16 + b[i] as u16).div_ceil(2) as u8);
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn adjust() {
+    let orig: [u8; 256] = core::array::from_fn(|i| i as u8);
+    for brightness in [-32768, -600, -255, -20, 0, 1, 77, 255, 400, 32767] {
+        for contrast in [0, 1, 64, 127, 128, 129, 200, 255] {
+            let mut got = orig;
+            pixel::adjust(&mut got, brightness, contrast);
+            let want: [u8; 256] = core::array::from_fn(|x| {
+                let scaled = ((x as i32 - 128) * contrast as i32).div_euclid(128);
+                (128 + brightness as i32 + scaled).clamp(0, 255) as u8
+            });
+            assert_eq!(got, want, "brightness {brightness}, contrast {contrast}");
+        }
+    }
+}
+
+#[test]
+pub fn invert() {
+    let mut got: [u8; 19] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x3b));
+    let want: [u8; 19] = core::array::from_fn(|i| 255 - got[i]);
+    pixel::invert(&mut got);
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn threshold() {
+    let src: [u8; 19] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x3b));
+    for cutoff in [0, 1, 100, 127, 128, 254, 255] {
+        let mut got = [0xaa_u8; 4];
+        pixel::threshold(&src, cutoff, &mut got);
+        let mut want = [0_u8; 4];
+        want[3] = 0xaa;
+        for (i, px) in src.iter().enumerate() {
+            if *px > cutoff {
+                want[i / 8] |= 0x80 >> (i % 8);
+            }
+        }
+        assert_eq!(got, want, "cutoff {cutoff}");
+    }
+}
+
+#[test]
+pub fn dither_ordered() {
+    use pixel::BayerMatrix;
+
+    // A flat gray at each level sets the proportional number of pixels.
+    for (matrix, levels) in [
+        (BayerMatrix::BAYER_2X2, 4),
+        (BayerMatrix::BAYER_4X4, 16),
+        (BayerMatrix::BAYER_8X8, 64),
+    ] {
+        for level in 0..=levels {
+            let gray = [(level * 256 / levels).min(255) as u8; 8];
+            let mut count = 0;
+            for y in 0..8 {
+                let mut bits = [0_u8];
+                pixel::dither_ordered(&gray, &mut bits, &matrix, y);
+                count += bits[0].count_ones();
+            }
+            assert_eq!(
+                count as usize,
+                level * 64 / levels,
+                "{levels} levels, level {level}"
+            );
+        }
+    }
+
+    // Custom matrices apply thresholds per position, and clear padding.
+    let matrix = BayerMatrix::from_rows(core::array::from_fn(|y| {
+        core::array::from_fn(|x| if y == 1 { 0 } else { (x * 30) as u8 })
+    }));
+    let src = [40_u8; 11];
+    let mut bits = [0_u8; 2];
+    pixel::dither_ordered(&src, &mut bits, &matrix, 0);
+    assert_eq!(bits, [0b1100_0000, 0b1100_0000]);
+    pixel::dither_ordered(&src, &mut bits, &matrix, 9);
+    assert_eq!(bits, [0b1111_1111, 0b1110_0000]);
+}
+
+#[test]
+pub fn rgb_to_gray() {
+    let src: [u8; 57] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x3b) ^ 0x0f);
+    let mut got = [0xaa_u8; 20];
+    pixel::rgb_to_gray(&src, &mut got[..19]);
+    let mut want = [0xaa_u8; 20];
+    for (i, px) in want[..19].iter_mut().enumerate() {
+        let (r, g, b) = (
+            src[i * 3] as u32,
+            src[i * 3 + 1] as u32,
+            src[i * 3 + 2] as u32,
+        );
+        *px = ((77 * r + 151 * g + 28 * b) / 256) as u8;
+    }
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn pack_rgb565() {
+    let r: [u8; 11] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x3b));
+    let g: [u8; 11] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x71) ^ 0x0f);
+    let b: [u8; 11] = core::array::from_fn(|i| (i as u8).wrapping_mul(0xc5) ^ 0x5a);
+    let mut got = [0xaa_u8; 24];
+    pixel::pack_rgb565(&r, &g, &b, &mut got);
+    let mut want = [0xaa_u8; 24];
+    for i in 0..11 {
+        let v = ((r[i] as u16 >> 3) << 11) | ((g[i] as u16 >> 2) << 5) | (b[i] as u16 >> 3);
+        want[i * 2..i * 2 + 2].copy_from_slice(&v.to_be_bytes());
+    }
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn unpack_rgb565() {
+    let src: [u8; 22] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x3b) ^ 0x0f);
+    let (mut r, mut g, mut b) = ([0_u8; 11], [0_u8; 11], [0_u8; 11]);
+    pixel::unpack_rgb565(&src, &mut r, &mut g, &mut b);
+    for i in 0..11 {
+        let v = u16::from_be_bytes([src[i * 2], src[i * 2 + 1]]);
+        let (r5, g6, b5) = ((v >> 11) as u8, ((v >> 5) & 0x3f) as u8, (v & 0x1f) as u8);
+        assert_eq!(r[i], (r5 << 3) | (r5 >> 2), "red {i}");
+        assert_eq!(g[i], (g6 << 2) | (g6 >> 4), "green {i}");
+        assert_eq!(b[i], (b5 << 3) | (b5 >> 2), "blue {i}");
+    }
+
+    // Packing again gives the original values, since the low bits are just
+    // copies of the high bits.
+    let mut repacked = [0_u8; 22];
+    pixel::pack_rgb565(&r, &g, &b, &mut repacked);
+    assert_eq!(repacked, src);
+}
+
+#[test]
+pub fn downscale2x() {
+    let src: [u8; 38] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x3b) ^ 0x0f);
+    let mut got = [0xaa_u8; 20];
+    pixel::downscale2x(&src, &mut got[..19]);
+    let mut want = [0xaa_u8; 20];
+    for (i, px) in want[..19].iter_mut().enumerate() {
+        *px = (src[i * 2] as u16 + src[i * 2 + 1] as u16).div_ceil(2) as u8;
+    }
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn box_blur3() {
+    for len in [0, 1, 2, 7, 8, 9, 16, 19] {
+        let src: Vec<u8> = (0..len)
+            .map(|i| (i as u8).wrapping_mul(0x3b) ^ 0x0f)
+            .collect();
+        let mut got = vec![0xaa_u8; len];
+        pixel::box_blur3(&src, &mut got);
+        let want: Vec<u8> = (0..len)
+            .map(|i| {
+                let left = src[i.saturating_sub(1)] as u32;
+                let right = src[(i + 1).min(len - 1)] as u32;
+                ((left + src[i] as u32 + right + 1) / 3) as u8
+            })
+            .collect();
+        assert_eq!(got, want, "length {len}");
+    }
+    let mut got = [0_u8; 8];
+    pixel::box_blur3(&[255; 8], &mut got);
+    assert_eq!(got, [255; 8]);
+}
+
+#[test]
+pub fn gradient_x() {
+    for len in [0, 1, 2, 7, 8, 9, 16, 19] {
+        let src: Vec<u8> = (0..len)
+            .map(|i| (i as u8).wrapping_mul(0x3b) ^ 0x0f)
+            .collect();
+        let mut got = vec![0xaa_u8; len];
+        pixel::gradient_x(&src, &mut got);
+        let want: Vec<u8> = (0..len)
+            .map(|i| src[i].abs_diff(src[(i + 1).min(len - 1)]))
+            .collect();
+        assert_eq!(got, want, "length {len}");
+    }
+}
+
+#[test]
+pub fn draw_glyph() {
+    let glyph = [
+        0b1000_0001,
+        0b0100_0010,
+        0b0010_0100,
+        0b0001_1000,
+        0b0001_1000,
+        0b0010_0100,
+        0b0100_0010,
+        0b1000_0001,
+    ];
+    for transparent_bg in [false, true] {
+        for (x, y) in [
+            (0, 0),
+            (2, 1),
+            (-3, -2),
+            (5, 4),
+            (-8, 0),
+            (10, 0),
+            (-9, 0),
+            (11, 0),
+            (100, 0),
+        ] {
+            let mut got = [9_u8; 10 * 7];
+            pixel::draw_glyph(&mut got, 10, &glyph, x, y, 1, 0, transparent_bg);
+            let mut want = [9_u8; 10 * 7];
+            for (gy, bits) in glyph.iter().enumerate() {
+                for gx in 0..8 {
+                    let (px, py) = (x + gx as isize, y + gy as isize);
+                    if !(0..10).contains(&px) || !(0..7).contains(&py) {
+                        continue;
+                    }
+                    let set = bits & (0x80 >> gx) != 0;
+                    let i = py as usize * 10 + px as usize;
+                    if set {
+                        want[i] = 1;
+                    } else if !transparent_bg {
+                        want[i] = 0;
+                    }
+                }
+            }
+            assert_eq!(got, want, "({x}, {y}), transparent {transparent_bg}");
+        }
+    }
+}
+
+#[test]
+pub fn scale2x_row() {
+    let src: [u8; 19] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x3b) ^ 0x0f);
+    for len in [0, 1, 2, 15, 16, 17, 37, 38] {
+        let mut got = [0xaa_u8; 38];
+        pixel::scale2x_row(&src, &mut got[..len]);
+        let want: [u8; 38] = core::array::from_fn(|i| if i < len { src[i / 2] } else { 0xaa });
+        assert_eq!(got, want, "length {len}");
+    }
+}
@@ -0,0 +1,806 @@
+//! Straightforward per-element implementations of the operations on
+//! [`crate::u8x8`] and [`crate::mask8x8`], for differential testing.
+//!
+//! The methods of the vector types use bit-manipulation tricks that can
+//! be hard to verify by inspection. The functions in this module instead
+//! convert their arguments to arrays and loop over the elements one at a
+//! time, so that they are obviously correct even though they are slow.
+//! Fuzzers can then check that both implementations agree:
+//!
+//! ```rust
+//! # use eight_bytes::{u8x8, reference};
+//! let a = u8x8::from_array([1, 2, 3, 4, 250, 251, 252, 253]);
+//! let b = u8x8::splat(5);
+//! assert_eq!(a.saturating_add(b), reference::u8x8::saturating_add(a, b));
+//! ```
+//!
+//! This module is available only when the `reference` feature is enabled.
+
+/// Per-element implementations of the methods of [`crate::u8x8`].
+pub mod u8x8 {
+    use crate::{i8x8, mask8x8, u8x8, u16x4};
+
+    #[inline(always)]
+    fn map(v: u8x8, f: impl Fn(u8) -> u8) -> u8x8 {
+        u8x8::from_array(v.to_array().map(f))
+    }
+
+    #[inline(always)]
+    fn zip(a: u8x8, b: u8x8, f: impl Fn(u8, u8) -> u8) -> u8x8 {
+        let (a, b) = (a.to_array(), b.to_array());
+        u8x8::from_array(core::array::from_fn(|i| f(a[i], b[i])))
+    }
+
+    #[inline(always)]
+    fn compare(a: u8x8, b: u8x8, f: impl Fn(u8, u8) -> bool) -> mask8x8 {
+        let (a, b) = (a.to_array(), b.to_array());
+        mask8x8::from_array(core::array::from_fn(|i| f(a[i], b[i])))
+    }
+
+    /// Reference implementation of [`u8x8::get`].
+    pub fn get(v: u8x8, i: usize) -> u8 {
+        v.to_array()[i]
+    }
+
+    /// Reference implementation of [`u8x8::with_lane`].
+    pub fn with_lane(v: u8x8, i: usize, x: u8) -> u8x8 {
+        let mut a = v.to_array();
+        a[i] = x;
+        u8x8::from_array(a)
+    }
+
+    /// Reference implementation of [`u8x8::extract`].
+    pub fn extract<const I: usize>(v: u8x8) -> u8 {
+        v.to_array()[I]
+    }
+
+    /// Reference implementation of [`u8x8::insert`].
+    pub fn insert<const I: usize>(v: u8x8, x: u8) -> u8x8 {
+        let mut a = v.to_array();
+        a[I] = x;
+        u8x8::from_array(a)
+    }
+
+    /// Reference implementation of [`u8x8::broadcast_lane`].
+    pub fn broadcast_lane(v: u8x8, i: usize) -> u8x8 {
+        u8x8::from_array([v.to_array()[i]; 8])
+    }
+
+    /// Reference implementation of [`u8x8::splat`].
+    pub fn splat(v: u8) -> u8x8 {
+        u8x8::from_array([v; 8])
+    }
+
+    /// Reference implementation of [`u8x8::complement`].
+    pub fn complement(v: u8x8) -> u8x8 {
+        map(v, |a| !a)
+    }
+
+    /// Reference implementation of [`u8x8::bitor`].
+    pub fn bitor(a: u8x8, b: u8x8) -> u8x8 {
+        zip(a, b, |a, b| a | b)
+    }
+
+    /// Reference implementation of [`u8x8::bitand`].
+    pub fn bitand(a: u8x8, b: u8x8) -> u8x8 {
+        zip(a, b, |a, b| a & b)
+    }
+
+    /// Reference implementation of [`u8x8::bitxor`].
+    pub fn bitxor(a: u8x8, b: u8x8) -> u8x8 {
+        zip(a, b, |a, b| a ^ b)
+    }
+
+    /// Reference implementation of [`u8x8::equals`].
+    pub fn equals(a: u8x8, b: u8x8) -> mask8x8 {
+        compare(a, b, |a, b| a == b)
+    }
+
+    /// Reference implementation of [`u8x8::not_equals`].
+    pub fn not_equals(a: u8x8, b: u8x8) -> mask8x8 {
+        compare(a, b, |a, b| a != b)
+    }
+
+    /// Reference implementation of [`u8x8::less_than`].
+    pub fn less_than(a: u8x8, b: u8x8) -> mask8x8 {
+        compare(a, b, |a, b| a < b)
+    }
+
+    /// Reference implementation of [`u8x8::greater_than`].
+    pub fn greater_than(a: u8x8, b: u8x8) -> mask8x8 {
+        compare(a, b, |a, b| a > b)
+    }
+
+    /// Reference implementation of [`u8x8::less_equal`].
+    pub fn less_equal(a: u8x8, b: u8x8) -> mask8x8 {
+        compare(a, b, |a, b| a <= b)
+    }
+
+    /// Reference implementation of [`u8x8::greater_equal`].
+    pub fn greater_equal(a: u8x8, b: u8x8) -> mask8x8 {
+        compare(a, b, |a, b| a >= b)
+    }
+
+    /// Reference implementation of [`u8x8::in_range`].
+    pub fn in_range(v: u8x8, lo: u8, hi: u8) -> mask8x8 {
+        mask8x8::from_array(v.to_array().map(|v| (lo..=hi).contains(&v)))
+    }
+
+    /// Reference implementation of [`u8x8::is_any_of`].
+    pub fn is_any_of(v: u8x8, set: &[u8]) -> mask8x8 {
+        mask8x8::from_array(v.to_array().map(|v| set.contains(&v)))
+    }
+
+    /// Reference implementation of [`u8x8::is_any_of_array`].
+    pub fn is_any_of_array<const N: usize>(v: u8x8, set: [u8; N]) -> mask8x8 {
+        mask8x8::from_array(v.to_array().map(|v| set.contains(&v)))
+    }
+
+    /// Reference implementation of [`u8x8::contains`].
+    pub fn contains(v: u8x8, x: u8) -> bool {
+        v.to_array().contains(&x)
+    }
+
+    /// Reference implementation of [`u8x8::count_eq`].
+    pub fn count_eq(v: u8x8, x: u8) -> u32 {
+        v.to_array().iter().filter(|v| **v == x).count() as u32
+    }
+
+    /// Reference implementation of [`u8x8::first_eq`].
+    pub fn first_eq(v: u8x8, x: u8) -> Option<usize> {
+        v.to_array().iter().position(|v| *v == x)
+    }
+
+    /// Reference implementation of [`u8x8::wrapping_add`].
+    pub fn wrapping_add(a: u8x8, b: u8x8) -> u8x8 {
+        zip(a, b, u8::wrapping_add)
+    }
+
+    /// Reference implementation of [`u8x8::saturating_add`].
+    pub fn saturating_add(a: u8x8, b: u8x8) -> u8x8 {
+        zip(a, b, u8::saturating_add)
+    }
+
+    /// Reference implementation of [`u8x8::reduce_sum`].
+    pub fn reduce_sum(v: u8x8) -> u64 {
+        v.to_array().into_iter().map(u64::from).sum()
+    }
+
+    /// Reference implementation of [`u8x8::reduce_and`].
+    pub fn reduce_and(v: u8x8) -> u8 {
+        v.to_array().into_iter().fold(0xff, |a, b| a & b)
+    }
+
+    /// Reference implementation of [`u8x8::reduce_or`].
+    pub fn reduce_or(v: u8x8) -> u8 {
+        v.to_array().into_iter().fold(0, |a, b| a | b)
+    }
+
+    /// Reference implementation of [`u8x8::reduce_xor`].
+    pub fn reduce_xor(v: u8x8) -> u8 {
+        v.to_array().into_iter().fold(0, |a, b| a ^ b)
+    }
+
+    /// Reference implementation of [`u8x8::wrapping_sub`].
+    pub fn wrapping_sub(a: u8x8, b: u8x8) -> u8x8 {
+        zip(a, b, u8::wrapping_sub)
+    }
+
+    /// Reference implementation of [`u8x8::saturating_sub`].
+    pub fn saturating_sub(a: u8x8, b: u8x8) -> u8x8 {
+        zip(a, b, u8::saturating_sub)
+    }
+
+    /// Reference implementation of [`u8x8::abs_difference`].
+    pub fn abs_difference(a: u8x8, b: u8x8) -> u8x8 {
+        zip(a, b, u8::abs_diff)
+    }
+
+    /// Reference implementation of [`u8x8::max`].
+    pub fn max(a: u8x8, b: u8x8) -> u8x8 {
+        zip(a, b, core::cmp::max)
+    }
+
+    /// Reference implementation of [`u8x8::min`].
+    pub fn min(a: u8x8, b: u8x8) -> u8x8 {
+        zip(a, b, core::cmp::min)
+    }
+
+    /// Reference implementation of [`u8x8::mean`].
+    pub fn mean(a: u8x8, b: u8x8) -> u8x8 {
+        zip(a, b, |a, b| ((a as u16 + b as u16) / 2) as u8)
+    }
+
+    /// Reference implementation of [`u8x8::to_i8x8_saturating`].
+    pub fn to_i8x8_saturating(v: u8x8) -> i8x8 {
+        i8x8::from_array(v.to_array().map(|v| v.min(127) as i8))
+    }
+
+    /// Reference implementation of [`u8x8::widen`].
+    pub fn widen(v: u8x8) -> (u16x4, u16x4) {
+        let a = v.to_array().map(u16::from);
+        (
+            u16x4::from_array([a[0], a[1], a[2], a[3]]),
+            u16x4::from_array([a[4], a[5], a[6], a[7]]),
+        )
+    }
+
+    /// Reference implementation of [`u8x8::shuffle`].
+    pub fn shuffle<
+        const I0: usize,
+        const I1: usize,
+        const I2: usize,
+        const I3: usize,
+        const I4: usize,
+        const I5: usize,
+        const I6: usize,
+        const I7: usize,
+    >(
+        v: u8x8,
+    ) -> u8x8 {
+        let a = v.to_array();
+        u8x8::from_array([I0, I1, I2, I3, I4, I5, I6, I7].map(|i| a[i]))
+    }
+
+    /// Reference implementation of [`u8x8::shuffle_dyn`].
+    pub fn shuffle_dyn(v: u8x8, indices: u8x8) -> u8x8 {
+        let a = v.to_array();
+        map(indices, |i| a.get(i as usize).copied().unwrap_or(0))
+    }
+
+    /// Reference implementation of [`u8x8::interleave`].
+    pub fn interleave(a: u8x8, b: u8x8) -> (u8x8, u8x8) {
+        let (a, b) = (a.to_array(), b.to_array());
+        let r: [u8; 16] = core::array::from_fn(|i| if i % 2 == 0 { a[i / 2] } else { b[i / 2] });
+        (
+            u8x8::from_array(r[..8].try_into().unwrap()),
+            u8x8::from_array(r[8..].try_into().unwrap()),
+        )
+    }
+
+    /// Reference implementation of [`u8x8::deinterleave`].
+    pub fn deinterleave(a: u8x8, b: u8x8) -> (u8x8, u8x8) {
+        let (a, b) = (a.to_array(), b.to_array());
+        let get = |i: usize| if i < 8 { a[i] } else { b[i - 8] };
+        (
+            u8x8::from_array(core::array::from_fn(|i| get(i * 2))),
+            u8x8::from_array(core::array::from_fn(|i| get(i * 2 + 1))),
+        )
+    }
+
+    /// Reference implementation of [`u8x8::rotate_lanes_left`].
+    pub fn rotate_lanes_left(v: u8x8, n: u32) -> u8x8 {
+        let mut a = v.to_array();
+        a.rotate_left(n as usize % 8);
+        u8x8::from_array(a)
+    }
+
+    /// Reference implementation of [`u8x8::rotate_lanes_right`].
+    pub fn rotate_lanes_right(v: u8x8, n: u32) -> u8x8 {
+        let mut a = v.to_array();
+        a.rotate_right(n as usize % 8);
+        u8x8::from_array(a)
+    }
+
+    /// Reference implementation of [`u8x8::swap_bytes_u16`].
+    pub fn swap_bytes_u16(v: u8x8) -> u8x8 {
+        let a = v.to_array();
+        u8x8::from_array(core::array::from_fn(|i| a[i ^ 1]))
+    }
+
+    /// Reference implementation of [`u8x8::swap_bytes_u32`].
+    pub fn swap_bytes_u32(v: u8x8) -> u8x8 {
+        let a = v.to_array();
+        u8x8::from_array(core::array::from_fn(|i| a[i ^ 3]))
+    }
+
+    /// Reference implementation of [`u8x8::transpose_bits`].
+    pub fn transpose_bits(v: u8x8) -> u8x8 {
+        let a = v.to_array();
+        u8x8::from_array(core::array::from_fn(|i| {
+            (0..8).fold(0, |acc, b| acc | (((a[b] >> i) & 1) << b))
+        }))
+    }
+
+    /// Returns the pixel at row `r` and column `c` of a 1bpp tile where
+    /// the most significant bit of each row is the leftmost column.
+    fn tile_pixel(a: &[u8; 8], r: usize, c: usize) -> bool {
+        a[r] & (0x80 >> c) != 0
+    }
+
+    /// Builds a 1bpp tile by calling `f` with the row and column of each
+    /// pixel.
+    fn tile_from_fn(f: impl Fn(usize, usize) -> bool) -> u8x8 {
+        u8x8::from_array(core::array::from_fn(|r| {
+            (0..8).fold(0, |acc, c| if f(r, c) { acc | (0x80 >> c) } else { acc })
+        }))
+    }
+
+    /// Reference implementation of [`u8x8::flip_bits_horizontal`].
+    pub fn flip_bits_horizontal(v: u8x8) -> u8x8 {
+        let a = v.to_array();
+        tile_from_fn(|r, c| tile_pixel(&a, r, 7 - c))
+    }
+
+    /// Reference implementation of [`u8x8::flip_bits_vertical`].
+    pub fn flip_bits_vertical(v: u8x8) -> u8x8 {
+        let a = v.to_array();
+        tile_from_fn(|r, c| tile_pixel(&a, 7 - r, c))
+    }
+
+    /// Reference implementation of [`u8x8::rotate_bits_90_cw`].
+    pub fn rotate_bits_90_cw(v: u8x8) -> u8x8 {
+        let a = v.to_array();
+        tile_from_fn(|r, c| tile_pixel(&a, 7 - c, r))
+    }
+
+    /// Reference implementation of [`u8x8::rotate_bits_90_ccw`].
+    pub fn rotate_bits_90_ccw(v: u8x8) -> u8x8 {
+        let a = v.to_array();
+        tile_from_fn(|r, c| tile_pixel(&a, c, 7 - r))
+    }
+
+    /// Reference implementation of [`u8x8::wrapping_mul`].
+    pub fn wrapping_mul(a: u8x8, b: u8x8) -> u8x8 {
+        zip(a, b, u8::wrapping_mul)
+    }
+
+    /// Reference implementation of [`u8x8::shl`].
+    pub fn shl(v: u8x8, n: u32) -> u8x8 {
+        map(v, |v| v.checked_shl(n).unwrap_or(0))
+    }
+
+    /// Reference implementation of [`u8x8::shr`].
+    pub fn shr(v: u8x8, n: u32) -> u8x8 {
+        map(v, |v| v.checked_shr(n).unwrap_or(0))
+    }
+
+    /// Reference implementation of [`u8x8::mul_scalar`].
+    pub fn mul_scalar(v: u8x8, k: u8) -> u8x8 {
+        map(v, |v| v.wrapping_mul(k))
+    }
+
+    /// Reference implementation of [`u8x8::div_scalar`].
+    pub fn div_scalar(v: u8x8, d: u8) -> u8x8 {
+        map(v, |v| v / d)
+    }
+
+    /// Reference implementation of [`u8x8::div_const`].
+    pub fn div_const<const D: u8>(v: u8x8) -> u8x8 {
+        map(v, |v| v / D)
+    }
+
+    /// Reference implementation of [`u8x8::rem_scalar`].
+    pub fn rem_scalar(v: u8x8, d: u8) -> u8x8 {
+        map(v, |v| v % d)
+    }
+
+    /// Reference implementation of [`u8x8::rem_pow2`].
+    pub fn rem_pow2(v: u8x8, shift: u32) -> u8x8 {
+        if shift >= 8 {
+            // The divisor exceeds every element.
+            return v;
+        }
+        map(v, |v| v % (1_u8 << shift))
+    }
+
+    /// Reference implementation of [`u8x8::mod_mask`].
+    pub fn mod_mask(v: u8x8, mask: u8) -> u8x8 {
+        map(v, |v| v & mask)
+    }
+
+    /// Reference implementation of [`u8x8::saturating_mul`].
+    pub fn saturating_mul(a: u8x8, b: u8x8) -> u8x8 {
+        zip(a, b, u8::saturating_mul)
+    }
+
+    /// Reference implementation of [`u8x8::widening_mul`].
+    pub fn widening_mul(a: u8x8, b: u8x8) -> (u16x4, u16x4) {
+        let (a, b) = (a.to_array(), b.to_array());
+        let p: [u16; 8] = core::array::from_fn(|i| a[i] as u16 * b[i] as u16);
+        (
+            u16x4::from_array([p[0], p[1], p[2], p[3]]),
+            u16x4::from_array([p[4], p[5], p[6], p[7]]),
+        )
+    }
+
+    /// Reference implementation of [`u8x8::morton_encode`].
+    pub fn morton_encode(x: u8x8, y: u8x8) -> (u16x4, u16x4) {
+        let (x, y) = (x.to_array(), y.to_array());
+        let codes: [u16; 8] = core::array::from_fn(|i| {
+            (0..8).fold(0, |acc, b| {
+                acc | ((((x[i] >> b) & 1) as u16) << (b * 2))
+                    | ((((y[i] >> b) & 1) as u16) << (b * 2 + 1))
+            })
+        });
+        (
+            u16x4::from_array([codes[0], codes[1], codes[2], codes[3]]),
+            u16x4::from_array([codes[4], codes[5], codes[6], codes[7]]),
+        )
+    }
+
+    /// Reference implementation of [`u8x8::pairwise_add`].
+    pub fn pairwise_add(v: u8x8) -> u16x4 {
+        let a = v.to_array().map(u16::from);
+        u16x4::from_array(core::array::from_fn(|i| a[i * 2] + a[i * 2 + 1]))
+    }
+
+    /// Reference implementation of [`u8x8::clamp`].
+    pub fn clamp(v: u8x8, lo: u8x8, hi: u8x8) -> u8x8 {
+        let (v, lo, hi) = (v.to_array(), lo.to_array(), hi.to_array());
+        u8x8::from_array(core::array::from_fn(|i| v[i].max(lo[i]).min(hi[i])))
+    }
+
+    /// Reference implementation of [`u8x8::clamp_scalar`].
+    pub fn clamp_scalar(v: u8x8, lo: u8, hi: u8) -> u8x8 {
+        map(v, |v| v.max(lo).min(hi))
+    }
+
+    /// Reference implementation of [`u8x8::lerp`].
+    pub fn lerp(a: u8x8, b: u8x8, t: u8) -> u8x8 {
+        let t = t as u32;
+        zip(a, b, |a, b| {
+            ((a as u32 * (255 - t) + b as u32 * t) / 255) as u8
+        })
+    }
+
+    /// Reference implementation of [`u8x8::mul_frac`].
+    pub fn mul_frac(a: u8x8, b: u8x8) -> u8x8 {
+        zip(a, b, |a, b| ((a as u32 * b as u32 + 127) / 255) as u8)
+    }
+
+    /// Reference implementation of [`u8x8::overflowing_add`].
+    pub fn overflowing_add(a: u8x8, b: u8x8) -> (u8x8, mask8x8) {
+        let (a, b) = (a.to_array(), b.to_array());
+        let r: [(u8, bool); 8] = core::array::from_fn(|i| a[i].overflowing_add(b[i]));
+        (
+            u8x8::from_array(r.map(|(v, _)| v)),
+            mask8x8::from_array(r.map(|(_, o)| o)),
+        )
+    }
+
+    /// Reference implementation of [`u8x8::borrowing_sub`].
+    pub fn borrowing_sub(a: u8x8, b: u8x8) -> (u8x8, mask8x8) {
+        let (a, b) = (a.to_array(), b.to_array());
+        let r: [(u8, bool); 8] = core::array::from_fn(|i| a[i].overflowing_sub(b[i]));
+        (
+            u8x8::from_array(r.map(|(v, _)| v)),
+            mask8x8::from_array(r.map(|(_, o)| o)),
+        )
+    }
+
+    /// Reference implementation of [`u8x8::checked_add`].
+    pub fn checked_add(a: u8x8, b: u8x8) -> Option<u8x8> {
+        let (a, b) = (a.to_array(), b.to_array());
+        let mut r = [0; 8];
+        for i in 0..8 {
+            r[i] = a[i].checked_add(b[i])?;
+        }
+        Some(u8x8::from_array(r))
+    }
+
+    /// Reference implementation of [`u8x8::checked_sub`].
+    pub fn checked_sub(a: u8x8, b: u8x8) -> Option<u8x8> {
+        let (a, b) = (a.to_array(), b.to_array());
+        let mut r = [0; 8];
+        for i in 0..8 {
+            r[i] = a[i].checked_sub(b[i])?;
+        }
+        Some(u8x8::from_array(r))
+    }
+
+    /// Reference implementation of [`u8x8::add_masked`].
+    pub fn add_masked(a: u8x8, b: u8x8, mask: mask8x8) -> u8x8 {
+        let (a, b, m) = (a.to_array(), b.to_array(), mask.to_array());
+        u8x8::from_array(core::array::from_fn(|i| {
+            if m[i] { a[i].wrapping_add(b[i]) } else { a[i] }
+        }))
+    }
+
+    /// Reference implementation of [`u8x8::sub_masked`].
+    pub fn sub_masked(a: u8x8, b: u8x8, mask: mask8x8) -> u8x8 {
+        let (a, b, m) = (a.to_array(), b.to_array(), mask.to_array());
+        u8x8::from_array(core::array::from_fn(|i| {
+            if m[i] { a[i].wrapping_sub(b[i]) } else { a[i] }
+        }))
+    }
+
+    /// Reference implementation of [`u8x8::carrying_add`].
+    pub fn carrying_add(a: u8x8, b: u8x8, carry_in: mask8x8) -> (u8x8, mask8x8) {
+        let (a, b, c) = (a.to_array(), b.to_array(), carry_in.to_array());
+        let r: [u16; 8] = core::array::from_fn(|i| a[i] as u16 + b[i] as u16 + c[i] as u16);
+        (
+            u8x8::from_array(r.map(|v| v as u8)),
+            mask8x8::from_array(r.map(|v| v > 255)),
+        )
+    }
+
+    /// Reference implementation of [`u8x8::rounding_mean`].
+    pub fn rounding_mean(a: u8x8, b: u8x8) -> u8x8 {
+        zip(a, b, |a, b| (a as u16 + b as u16).div_ceil(2) as u8)
+    }
+
+    /// Reference implementation of [`u8x8::leading_zeros`].
+    pub fn leading_zeros(v: u8x8) -> u8x8 {
+        map(v, |v| v.leading_zeros() as u8)
+    }
+
+    /// Reference implementation of [`u8x8::trailing_zeros`].
+    pub fn trailing_zeros(v: u8x8) -> u8x8 {
+        map(v, |v| v.trailing_zeros() as u8)
+    }
+
+    /// Reference implementation of [`u8x8::ilog2`].
+    pub fn ilog2(v: u8x8) -> u8x8 {
+        map(v, |v| v.checked_ilog2().map_or(255, |l| l as u8))
+    }
+
+    /// Reference implementation of [`u8x8::checked_ilog2`].
+    pub fn checked_ilog2(v: u8x8) -> Option<u8x8> {
+        let mut ret = [0_u8; 8];
+        for (dst, v) in ret.iter_mut().zip(v.to_array()) {
+            *dst = v.checked_ilog2()? as u8;
+        }
+        Some(u8x8::from_array(ret))
+    }
+
+    /// Reference implementation of [`u8x8::is_power_of_two`].
+    pub fn is_power_of_two(v: u8x8) -> mask8x8 {
+        mask8x8::from_array(v.to_array().map(u8::is_power_of_two))
+    }
+
+    /// Reference implementation of [`u8x8::is_zero_or_power_of_two`].
+    pub fn is_zero_or_power_of_two(v: u8x8) -> mask8x8 {
+        mask8x8::from_array(v.to_array().map(|v| v.count_ones() <= 1))
+    }
+
+    /// Reference implementation of [`u8x8::lookup_nibble`].
+    pub fn lookup_nibble(v: u8x8, table: [u8; 16]) -> u8x8 {
+        map(v, |a| table[(a & 0xf) as usize])
+    }
+
+    /// Reference implementation of [`u8x8::lookup_high_nibble`].
+    pub fn lookup_high_nibble(v: u8x8, table: [u8; 16]) -> u8x8 {
+        map(v, |a| table[(a >> 4) as usize])
+    }
+
+    /// Reference implementation of [`u8x8::wrapping_prefix_sum`].
+    pub fn wrapping_prefix_sum(v: u8x8) -> u8x8 {
+        let mut a = v.to_array();
+        for i in 1..8 {
+            a[i] = a[i].wrapping_add(a[i - 1]);
+        }
+        u8x8::from_array(a)
+    }
+
+    /// Reference implementation of [`u8x8::saturating_prefix_sum`].
+    pub fn saturating_prefix_sum(v: u8x8) -> u8x8 {
+        let mut a = v.to_array();
+        for i in 1..8 {
+            a[i] = a[i].saturating_add(a[i - 1]);
+        }
+        u8x8::from_array(a)
+    }
+
+    /// Reference implementation of [`u8x8::prefix_max`].
+    pub fn prefix_max(v: u8x8) -> u8x8 {
+        let mut a = v.to_array();
+        for i in 1..8 {
+            a[i] = a[i].max(a[i - 1]);
+        }
+        u8x8::from_array(a)
+    }
+
+    /// Reference implementation of [`u8x8::popcount`].
+    pub fn popcount(v: u8x8) -> u8x8 {
+        map(v, |a| a.count_ones() as u8)
+    }
+
+    /// Reference implementation of [`u8x8::extract_bits`].
+    pub fn extract_bits(v: u8x8, mask: u8x8) -> u8x8 {
+        zip(v, mask, |v, mask| {
+            let mut ret = 0;
+            let mut dest = 0;
+            for b in 0..8 {
+                if mask & (1 << b) != 0 {
+                    ret |= ((v >> b) & 1) << dest;
+                    dest += 1;
+                }
+            }
+            ret
+        })
+    }
+
+    /// Reference implementation of [`u8x8::deposit_bits`].
+    pub fn deposit_bits(v: u8x8, mask: u8x8) -> u8x8 {
+        zip(v, mask, |v, mask| {
+            let mut ret = 0;
+            let mut src = 0;
+            for b in 0..8 {
+                if mask & (1 << b) != 0 {
+                    ret |= ((v >> src) & 1) << b;
+                    src += 1;
+                }
+            }
+            ret
+        })
+    }
+
+    /// Reference implementation of [`u8x8::hamming_distance`].
+    pub fn hamming_distance(a: u8x8, b: u8x8) -> u32 {
+        let (a, b) = (a.to_array(), b.to_array());
+        (0..8).map(|i| (a[i] ^ b[i]).count_ones()).sum()
+    }
+}
+
+/// Per-element implementations of the methods of [`crate::mask8x8`].
+pub mod mask8x8 {
+    use crate::{mask8x8, u8x8};
+
+    #[inline(always)]
+    fn zip(a: mask8x8, b: mask8x8, f: impl Fn(bool, bool) -> bool) -> mask8x8 {
+        let (a, b) = (a.to_array(), b.to_array());
+        mask8x8::from_array(core::array::from_fn(|i| f(a[i], b[i])))
+    }
+
+    /// Reference implementation of [`mask8x8::from_bitmask_le`].
+    pub fn from_bitmask_le(mask: u8) -> mask8x8 {
+        mask8x8::from_array(core::array::from_fn(|i| mask & (1 << i) != 0))
+    }
+
+    /// Reference implementation of [`mask8x8::from_bitmask_be`].
+    pub fn from_bitmask_be(mask: u8) -> mask8x8 {
+        mask8x8::from_array(core::array::from_fn(|i| mask & (0x80 >> i) != 0))
+    }
+
+    /// Reference implementation of [`mask8x8::to_bitmask_le`].
+    pub fn to_bitmask_le(m: mask8x8) -> u8 {
+        let a = m.to_array();
+        (0..8).filter(|i| a[*i]).fold(0, |acc, i| acc | (1 << i))
+    }
+
+    /// Reference implementation of [`mask8x8::to_bitmask_be`].
+    pub fn to_bitmask_be(m: mask8x8) -> u8 {
+        let a = m.to_array();
+        (0..8).filter(|i| a[*i]).fold(0, |acc, i| acc | (0x80 >> i))
+    }
+
+    /// Reference implementation of [`mask8x8::to_u8x8`].
+    pub fn to_u8x8(m: mask8x8) -> u8x8 {
+        to_u8x8_with(m, 1)
+    }
+
+    /// Reference implementation of [`mask8x8::to_u8x8_with`].
+    pub fn to_u8x8_with(m: mask8x8, v: u8) -> u8x8 {
+        select(m, v, 0)
+    }
+
+    /// Reference implementation of [`mask8x8::not`].
+    pub fn not(m: mask8x8) -> mask8x8 {
+        mask8x8::from_array(m.to_array().map(|a| !a))
+    }
+
+    /// Reference implementation of [`mask8x8::or`].
+    pub fn or(a: mask8x8, b: mask8x8) -> mask8x8 {
+        zip(a, b, |a, b| a | b)
+    }
+
+    /// Reference implementation of [`mask8x8::and`].
+    pub fn and(a: mask8x8, b: mask8x8) -> mask8x8 {
+        zip(a, b, |a, b| a & b)
+    }
+
+    /// Reference implementation of [`mask8x8::select`].
+    pub fn select(m: mask8x8, true_value: u8, false_value: u8) -> u8x8 {
+        u8x8::from_array(
+            m.to_array()
+                .map(|a| if a { true_value } else { false_value }),
+        )
+    }
+
+    /// Reference implementation of [`mask8x8::count_true`].
+    pub fn count_true(m: mask8x8) -> u32 {
+        m.to_array().into_iter().filter(|a| *a).count() as u32
+    }
+
+    /// Reference implementation of [`mask8x8::count_false`].
+    pub fn count_false(m: mask8x8) -> u32 {
+        m.to_array().into_iter().filter(|a| !*a).count() as u32
+    }
+}
+
+/// Per-element implementations of the methods of [`crate::u16x4`].
+pub mod u16x4 {
+    use crate::{mask16x4, u8x8, u16x4};
+
+    #[inline(always)]
+    fn compare(a: u16x4, b: u16x4, f: impl Fn(&u16, &u16) -> bool) -> mask16x4 {
+        let (a, b) = (a.to_array(), b.to_array());
+        mask16x4::from_array(core::array::from_fn(|i| f(&a[i], &b[i])))
+    }
+
+    /// Reference implementation of [`u16x4::equals`].
+    pub fn equals(a: u16x4, b: u16x4) -> mask16x4 {
+        compare(a, b, u16::eq)
+    }
+
+    /// Reference implementation of [`u16x4::less_than`].
+    pub fn less_than(a: u16x4, b: u16x4) -> mask16x4 {
+        compare(a, b, u16::lt)
+    }
+
+    /// Reference implementation of [`u16x4::greater_than`].
+    pub fn greater_than(a: u16x4, b: u16x4) -> mask16x4 {
+        compare(a, b, u16::gt)
+    }
+
+    #[inline(always)]
+    fn zip(a: u16x4, b: u16x4, f: impl Fn(u16, u16) -> u16) -> u16x4 {
+        let (a, b) = (a.to_array(), b.to_array());
+        u16x4::from_array(core::array::from_fn(|i| f(a[i], b[i])))
+    }
+
+    /// Reference implementation of [`u16x4::wrapping_add`].
+    pub fn wrapping_add(a: u16x4, b: u16x4) -> u16x4 {
+        zip(a, b, u16::wrapping_add)
+    }
+
+    /// Reference implementation of [`u16x4::wrapping_sub`].
+    pub fn wrapping_sub(a: u16x4, b: u16x4) -> u16x4 {
+        zip(a, b, u16::wrapping_sub)
+    }
+
+    /// Reference implementation of [`u16x4::morton_decode`].
+    pub fn morton_decode(lo: u16x4, hi: u16x4) -> (u8x8, u8x8) {
+        let (lo, hi) = (lo.to_array(), hi.to_array());
+        let code = |i: usize| if i < 4 { lo[i] } else { hi[i - 4] };
+        let bits = |i: usize, first: u16| {
+            (0..8).fold(0, |acc, b| {
+                acc | ((((code(i) >> (b * 2 + first)) & 1) as u8) << b)
+            })
+        };
+        (
+            u8x8::from_array(core::array::from_fn(|i| bits(i, 0))),
+            u8x8::from_array(core::array::from_fn(|i| bits(i, 1))),
+        )
+    }
+
+    /// Reference implementation of [`u16x4::pack_saturating`].
+    pub fn pack_saturating(lo: u16x4, hi: u16x4) -> u8x8 {
+        let (lo, hi) = (lo.to_array(), hi.to_array());
+        u8x8::from_array(core::array::from_fn(|i| {
+            let v = if i < 4 { lo[i] } else { hi[i - 4] };
+            v.min(255) as u8
+        }))
+    }
+}
+
+/// Per-element implementations of the methods of [`crate::i8x8`].
+pub mod i8x8 {
+    use crate::{i8x8, mask8x8, u8x8};
+
+    fn compare(a: i8x8, b: i8x8, f: impl Fn(&i8, &i8) -> bool) -> mask8x8 {
+        let (a, b) = (a.to_array(), b.to_array());
+        mask8x8::from_array(core::array::from_fn(|i| f(&a[i], &b[i])))
+    }
+
+    /// Reference implementation of [`i8x8::equals`].
+    pub fn equals(a: i8x8, b: i8x8) -> mask8x8 {
+        compare(a, b, i8::eq)
+    }
+
+    /// Reference implementation of [`i8x8::less_than`].
+    pub fn less_than(a: i8x8, b: i8x8) -> mask8x8 {
+        compare(a, b, i8::lt)
+    }
+
+    /// Reference implementation of [`i8x8::greater_than`].
+    pub fn greater_than(a: i8x8, b: i8x8) -> mask8x8 {
+        compare(a, b, i8::gt)
+    }
+
+    /// Reference implementation of [`i8x8::to_u8x8_saturating`].
+    pub fn to_u8x8_saturating(v: i8x8) -> u8x8 {
+        u8x8::from_array(v.to_array().map(|v| v.max(0) as u8))
+    }
+}
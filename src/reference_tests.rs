@@ -0,0 +1,554 @@
+//! Differential tests comparing the vector types against the per-element
+//! implementations in [`crate::reference`].
+
+use super::*;
+
+/// Returns a deterministic sequence of vectors that includes both
+/// pseudorandom values and the boundary values that bit tricks tend to
+/// get wrong.
+fn sample_vectors() -> impl Iterator<Item = u8x8> {
+    const EDGES: [u8; 8] = [0x00, 0x01, 0x7f, 0x80, 0x81, 0xfe, 0xff, 0x40];
+    let mut state: u64 = 0x243f6a8885a308d3;
+    let random = core::iter::repeat_with(move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        u8x8::from_array(state.to_ne_bytes())
+    });
+    let edges = (0..64)
+        .map(|i| u8x8::from_array(core::array::from_fn(|j| EDGES[(i + j * (i / 8 + 1)) % 8])));
+    edges.chain(random.take(2000))
+}
+
+fn sample_pairs() -> impl Iterator<Item = (u8x8, u8x8)> {
+    sample_vectors().zip(sample_vectors().skip(7))
+}
+
+fn sample_masks() -> impl Iterator<Item = mask8x8> {
+    (0..=255).map(reference::mask8x8::from_bitmask_le)
+}
+
+#[test]
+pub fn u8x8_unary() {
+    for v in sample_vectors() {
+        assert_eq!(v.complement(), reference::u8x8::complement(v), "{v:?}");
+        assert_eq!(v.reduce_sum(), reference::u8x8::reduce_sum(v), "{v:?}");
+        for i in 0..8 {
+            assert_eq!(v.get(i), reference::u8x8::get(v, i), "{v:?} {i}");
+            assert_eq!(
+                v.broadcast_lane(i),
+                reference::u8x8::broadcast_lane(v, i),
+                "{v:?} {i}"
+            );
+            assert_eq!(
+                v.with_lane(i, !v.get(i)),
+                reference::u8x8::with_lane(v, i, !v.get(i)),
+                "{v:?} {i}"
+            );
+        }
+        macro_rules! extract_insert {
+            ($($i:literal)*) => {$(
+                assert_eq!(
+                    v.extract::<$i>(),
+                    reference::u8x8::extract::<$i>(v),
+                    "{v:?} {}",
+                    $i
+                );
+                assert_eq!(
+                    v.insert::<$i>(!v.get($i)),
+                    reference::u8x8::insert::<$i>(v, !v.get($i)),
+                    "{v:?} {}",
+                    $i
+                );
+            )*};
+        }
+        extract_insert!(0 1 2 3 4 5 6 7);
+        assert_eq!(v.reduce_and(), reference::u8x8::reduce_and(v), "{v:?}");
+        assert_eq!(v.reduce_or(), reference::u8x8::reduce_or(v), "{v:?}");
+        assert_eq!(v.reduce_xor(), reference::u8x8::reduce_xor(v), "{v:?}");
+        assert_eq!(v.popcount(), reference::u8x8::popcount(v), "{v:?}");
+        assert_eq!(
+            v.leading_zeros(),
+            reference::u8x8::leading_zeros(v),
+            "{v:?}"
+        );
+        assert_eq!(
+            v.trailing_zeros(),
+            reference::u8x8::trailing_zeros(v),
+            "{v:?}"
+        );
+        assert_eq!(v.ilog2(), reference::u8x8::ilog2(v), "{v:?}");
+        for v in [v, v | u8x8::splat(1)] {
+            assert_eq!(
+                v.checked_ilog2(),
+                reference::u8x8::checked_ilog2(v),
+                "{v:?}"
+            );
+        }
+        assert_eq!(
+            v.is_power_of_two(),
+            reference::u8x8::is_power_of_two(v),
+            "{v:?}"
+        );
+        assert_eq!(
+            v.is_zero_or_power_of_two(),
+            reference::u8x8::is_zero_or_power_of_two(v),
+            "{v:?}"
+        );
+        assert_eq!(
+            v.transpose_bits(),
+            reference::u8x8::transpose_bits(v),
+            "{v:?}"
+        );
+        assert_eq!(
+            v.flip_bits_horizontal(),
+            reference::u8x8::flip_bits_horizontal(v),
+            "{v:?}"
+        );
+        assert_eq!(
+            v.flip_bits_vertical(),
+            reference::u8x8::flip_bits_vertical(v),
+            "{v:?}"
+        );
+        assert_eq!(
+            v.rotate_bits_90_cw(),
+            reference::u8x8::rotate_bits_90_cw(v),
+            "{v:?}"
+        );
+        assert_eq!(
+            v.rotate_bits_90_ccw(),
+            reference::u8x8::rotate_bits_90_ccw(v),
+            "{v:?}"
+        );
+        assert_eq!(
+            v.swap_bytes_u16(),
+            reference::u8x8::swap_bytes_u16(v),
+            "{v:?}"
+        );
+        assert_eq!(
+            v.swap_bytes_u32(),
+            reference::u8x8::swap_bytes_u32(v),
+            "{v:?}"
+        );
+        macro_rules! shuffle {
+            ($([$($i:literal),*])*) => {$(
+                assert_eq!(
+                    v.shuffle::<$($i),*>(),
+                    reference::u8x8::shuffle::<$($i),*>(v),
+                    "{v:?} {:?}",
+                    [$($i),*]
+                );
+            )*};
+        }
+        shuffle!(
+            [0, 1, 2, 3, 4, 5, 6, 7]
+            [7, 6, 5, 4, 3, 2, 1, 0]
+            [2, 1, 0, 3, 6, 5, 4, 7]
+            [0, 0, 0, 0, 7, 7, 7, 7]
+            [1, 3, 5, 7, 0, 2, 4, 6]
+        );
+        for n in 0..=17 {
+            assert_eq!(
+                v.rotate_lanes_left(n),
+                reference::u8x8::rotate_lanes_left(v, n),
+                "{v:?} {n}"
+            );
+            assert_eq!(
+                v.rotate_lanes_right(n),
+                reference::u8x8::rotate_lanes_right(v, n),
+                "{v:?} {n}"
+            );
+        }
+        let table: [u8; 16] = core::array::from_fn(|i| (i as u8).wrapping_mul(73) ^ 0x5a);
+        assert_eq!(
+            v.lookup_nibble(table),
+            reference::u8x8::lookup_nibble(v, table),
+            "{v:?}"
+        );
+        assert_eq!(
+            v.lookup_high_nibble(table),
+            reference::u8x8::lookup_high_nibble(v, table),
+            "{v:?}"
+        );
+        assert_eq!(
+            v.wrapping_prefix_sum(),
+            reference::u8x8::wrapping_prefix_sum(v),
+            "{v:?}"
+        );
+        assert_eq!(
+            v.saturating_prefix_sum(),
+            reference::u8x8::saturating_prefix_sum(v),
+            "{v:?}"
+        );
+        assert_eq!(v.prefix_max(), reference::u8x8::prefix_max(v), "{v:?}");
+        assert_eq!(v.widen(), reference::u8x8::widen(v), "{v:?}");
+        assert_eq!(v.pairwise_add(), reference::u8x8::pairwise_add(v), "{v:?}");
+        assert_eq!(
+            v.to_i8x8_saturating(),
+            reference::u8x8::to_i8x8_saturating(v),
+            "{v:?}"
+        );
+        let s = v.cast_i8x8();
+        assert_eq!(
+            s.to_u8x8_saturating(),
+            reference::i8x8::to_u8x8_saturating(s),
+            "{s:?}"
+        );
+    }
+    for b in 0..=255 {
+        assert_eq!(u8x8::splat(b), reference::u8x8::splat(b));
+    }
+    // Stepping by an odd number visits every scalar many times over.
+    for (v, k) in sample_vectors().zip((0..).map(|i: u32| (i * 37) as u8)) {
+        assert_eq!(
+            v.mul_scalar(k),
+            reference::u8x8::mul_scalar(v, k),
+            "{v:?} {k}"
+        );
+    }
+    // Each vector is searched for each of its own elements and also for
+    // another value that it may or may not contain.
+    for (v, k) in sample_vectors().zip((0..).map(|i: u32| (i * 41) as u8)) {
+        for x in v.to_array().into_iter().chain([k]) {
+            assert_eq!(v.contains(x), reference::u8x8::contains(v, x), "{v:?} {x}");
+            assert_eq!(v.count_eq(x), reference::u8x8::count_eq(v, x), "{v:?} {x}");
+            assert_eq!(v.first_eq(x), reference::u8x8::first_eq(v, x), "{v:?} {x}");
+        }
+    }
+    // Every possible dividend appears in one of these vectors.
+    let all: Vec<u8x8> = (0..32)
+        .map(|i| u8x8::from_array(core::array::from_fn(|j| i * 8 + j as u8)))
+        .collect();
+    for d in 1..=255 {
+        for &v in all.iter() {
+            assert_eq!(
+                v.div_scalar(d),
+                reference::u8x8::div_scalar(v, d),
+                "{v:?} {d}"
+            );
+            assert_eq!(
+                v.rem_scalar(d),
+                reference::u8x8::rem_scalar(v, d),
+                "{v:?} {d}"
+            );
+        }
+    }
+    macro_rules! div_const {
+        ($($d:literal)*) => {$(
+            for &v in all.iter() {
+                assert_eq!(
+                    v.div_const::<$d>(),
+                    reference::u8x8::div_const::<$d>(v),
+                    "{v:?} {}",
+                    $d
+                );
+            }
+        )*};
+    }
+    div_const!(1 2 3 5 6 7 9 10 11 13 16 17 25 100 127 128 129 200 254 255);
+    for shift in (0..=10).chain([31, 32, 100, u32::MAX]) {
+        for &v in all.iter() {
+            assert_eq!(
+                v.rem_pow2(shift),
+                reference::u8x8::rem_pow2(v, shift),
+                "{v:?} {shift}"
+            );
+            assert_eq!(
+                v.shl(shift),
+                reference::u8x8::shl(v, shift),
+                "{v:?} {shift}"
+            );
+            assert_eq!(
+                v.shr(shift),
+                reference::u8x8::shr(v, shift),
+                "{v:?} {shift}"
+            );
+        }
+    }
+    for mask in 0..=255 {
+        for &v in all.iter() {
+            assert_eq!(
+                v.mod_mask(mask),
+                reference::u8x8::mod_mask(v, mask),
+                "{v:?} {mask}"
+            );
+        }
+    }
+}
+
+#[test]
+pub fn u8x8_mul_frac_exhaustive() {
+    for a in 0..=255 {
+        for b in (0..=255).step_by(8) {
+            let a = u8x8::splat(a);
+            let b = u8x8::from_array(core::array::from_fn(|i| b + i as u8));
+            assert_eq!(
+                a.mul_frac(b),
+                reference::u8x8::mul_frac(a, b),
+                "{a:?} {b:?}"
+            );
+        }
+    }
+}
+
+#[test]
+pub fn u8x8_binary() {
+    use reference::u8x8 as r;
+    for (a, b) in sample_pairs() {
+        assert_eq!(a.bitor(b), r::bitor(a, b), "{a:?} {b:?}");
+        assert_eq!(a.bitand(b), r::bitand(a, b), "{a:?} {b:?}");
+        assert_eq!(a.bitxor(b), r::bitxor(a, b), "{a:?} {b:?}");
+        assert_eq!(a.equals(b), r::equals(a, b), "{a:?} {b:?}");
+        assert_eq!(a.equals(a), r::equals(a, a), "{a:?}");
+        assert_eq!(a.not_equals(b), r::not_equals(a, b), "{a:?} {b:?}");
+        assert_eq!(a.not_equals(a), r::not_equals(a, a), "{a:?}");
+        assert_eq!(a.less_than(b), r::less_than(a, b), "{a:?} {b:?}");
+        assert_eq!(a.greater_than(b), r::greater_than(a, b), "{a:?} {b:?}");
+        assert_eq!(a.less_equal(b), r::less_equal(a, b), "{a:?} {b:?}");
+        let set = b.to_array();
+        for n in 0..=set.len() {
+            let set = &set[..n];
+            assert_eq!(a.is_any_of(set), r::is_any_of(a, set), "{a:?} {set:?}");
+        }
+        assert_eq!(
+            a.is_any_of_array(set),
+            r::is_any_of_array(a, set),
+            "{a:?} {set:?}"
+        );
+        for (lo, hi) in a.to_array().into_iter().zip(b.to_array()) {
+            assert_eq!(
+                a.in_range(lo, hi),
+                r::in_range(a, lo, hi),
+                "{a:?} {lo} {hi}"
+            );
+        }
+        assert_eq!(a.less_equal(a), r::less_equal(a, a), "{a:?}");
+        assert_eq!(a.greater_equal(b), r::greater_equal(a, b), "{a:?} {b:?}");
+        assert_eq!(a.greater_equal(a), r::greater_equal(a, a), "{a:?}");
+        assert_eq!(a.wrapping_add(b), r::wrapping_add(a, b), "{a:?} {b:?}");
+        assert_eq!(a.saturating_add(b), r::saturating_add(a, b), "{a:?} {b:?}");
+        assert_eq!(
+            a.overflowing_add(b),
+            r::overflowing_add(a, b),
+            "{a:?} {b:?}"
+        );
+        assert_eq!(a.wrapping_sub(b), r::wrapping_sub(a, b), "{a:?} {b:?}");
+        assert_eq!(a.saturating_sub(b), r::saturating_sub(a, b), "{a:?} {b:?}");
+        assert_eq!(a.borrowing_sub(b), r::borrowing_sub(a, b), "{a:?} {b:?}");
+        assert_eq!(
+            a.hamming_distance(b),
+            r::hamming_distance(a, b),
+            "{a:?} {b:?}"
+        );
+        assert_eq!(a.shuffle_dyn(b), r::shuffle_dyn(a, b), "{a:?} {b:?}");
+        // Most sample elements are out of range, so also try them modulo 8.
+        let idx = b.rem_scalar(8);
+        assert_eq!(a.shuffle_dyn(idx), r::shuffle_dyn(a, idx), "{a:?} {idx:?}");
+        assert_eq!(a.extract_bits(b), r::extract_bits(a, b), "{a:?} {b:?}");
+        assert_eq!(a.deposit_bits(b), r::deposit_bits(a, b), "{a:?} {b:?}");
+        assert_eq!(a.interleave(b), r::interleave(a, b), "{a:?} {b:?}");
+        assert_eq!(a.deinterleave(b), r::deinterleave(a, b), "{a:?} {b:?}");
+        assert_eq!(a.checked_add(b), r::checked_add(a, b), "{a:?} {b:?}");
+        assert_eq!(a.checked_sub(b), r::checked_sub(a, b), "{a:?} {b:?}");
+        for c in [mask8x8::ALL_FALSE, mask8x8::ALL_TRUE, a.less_than(b)] {
+            assert_eq!(
+                a.add_masked(b, c),
+                r::add_masked(a, b, c),
+                "{a:?} {b:?} {c:?}"
+            );
+            assert_eq!(
+                a.sub_masked(b, c),
+                r::sub_masked(a, b, c),
+                "{a:?} {b:?} {c:?}"
+            );
+            assert_eq!(
+                a.carrying_add(b, c),
+                r::carrying_add(a, b, c),
+                "{a:?} {b:?} {c:?}"
+            );
+        }
+        assert_eq!(a.abs_difference(b), r::abs_difference(a, b), "{a:?} {b:?}");
+        assert_eq!(a.max(b), r::max(a, b), "{a:?} {b:?}");
+        assert_eq!(a.min(b), r::min(a, b), "{a:?} {b:?}");
+        assert_eq!(a.mean(b), r::mean(a, b), "{a:?} {b:?}");
+        assert_eq!(a.rounding_mean(b), r::rounding_mean(a, b), "{a:?} {b:?}");
+        assert_eq!(a.wrapping_mul(b), r::wrapping_mul(a, b), "{a:?} {b:?}");
+        assert_eq!(a.saturating_mul(b), r::saturating_mul(a, b), "{a:?} {b:?}");
+        assert_eq!(a.widening_mul(b), r::widening_mul(a, b), "{a:?} {b:?}");
+        assert_eq!(a.morton_encode(b), r::morton_encode(a, b), "{a:?} {b:?}");
+        for t in [0, 1, 127, 128, 254, 255, a.to_array()[0]] {
+            assert_eq!(a.lerp(b, t), r::lerp(a, b, t), "{a:?} {b:?} {t}");
+        }
+        let (lo, hi) = (a.min(b), a.max(b));
+        for v in [a, b, a.mean(b), !a, u8x8::ZEROES, u8x8::splat(255)] {
+            assert_eq!(v.clamp(lo, hi), r::clamp(v, lo, hi), "{v:?} {lo:?} {hi:?}");
+        }
+        let (x, y) = (a.to_array()[0], b.to_array()[0]);
+        for (lo, hi) in [(x, y), (y, x), (0, 255), (x, x)] {
+            assert_eq!(
+                a.clamp_scalar(lo, hi),
+                r::clamp_scalar(a, lo, hi),
+                "{a:?} {lo} {hi}"
+            );
+        }
+    }
+}
+
+#[test]
+pub fn mask8x8_conversions() {
+    use reference::mask8x8 as r;
+    for bits in 0..=255 {
+        assert_eq!(mask8x8::from_bitmask_le(bits), r::from_bitmask_le(bits));
+        assert_eq!(mask8x8::from_bitmask_be(bits), r::from_bitmask_be(bits));
+    }
+    for m in sample_masks() {
+        assert_eq!(m.to_bitmask_le(), r::to_bitmask_le(m), "{m:?}");
+        assert_eq!(m.to_bitmask_be(), r::to_bitmask_be(m), "{m:?}");
+        assert_eq!(m.to_u8x8(), r::to_u8x8(m), "{m:?}");
+        assert_eq!(m.to_u8x8_with(0xa5), r::to_u8x8_with(m, 0xa5), "{m:?}");
+        assert_eq!(m.to_u8x8_with(0xff), r::to_u8x8_with(m, 0xff), "{m:?}");
+        assert_eq!(m.select(0x12, 0xfe), r::select(m, 0x12, 0xfe), "{m:?}");
+        assert_eq!(m.count_true(), r::count_true(m), "{m:?}");
+        assert_eq!(m.count_false(), r::count_false(m), "{m:?}");
+    }
+}
+
+#[test]
+pub fn mask8x8_logic() {
+    use reference::mask8x8 as r;
+    for a in sample_masks() {
+        assert_eq!(a.not(), r::not(a), "{a:?}");
+        for b in sample_masks().step_by(7) {
+            assert_eq!(a.or(b), r::or(a, b), "{a:?} {b:?}");
+            assert_eq!(a.and(b), r::and(a, b), "{a:?} {b:?}");
+        }
+    }
+}
+
+#[test]
+pub fn u16x4_binary() {
+    use reference::u16x4 as r;
+    for (a, b) in sample_pairs() {
+        // Each u8x8 sample also serves as a u16x4 sample.
+        let (a, b) = (u16x4::new(a.n), u16x4::new(b.n));
+        assert_eq!(a.equals(b), r::equals(a, b), "{a:?} {b:?}");
+        assert_eq!(a.equals(a), r::equals(a, a), "{a:?}");
+        assert_eq!(a.less_than(b), r::less_than(a, b), "{a:?} {b:?}");
+        assert_eq!(a.greater_than(b), r::greater_than(a, b), "{a:?} {b:?}");
+        assert_eq!(a.wrapping_add(b), r::wrapping_add(a, b), "{a:?} {b:?}");
+        assert_eq!(a.wrapping_sub(b), r::wrapping_sub(a, b), "{a:?} {b:?}");
+        assert_eq!(
+            u16x4::pack_saturating(a, b),
+            r::pack_saturating(a, b),
+            "{a:?} {b:?}"
+        );
+        assert_eq!(
+            u16x4::morton_decode(a, b),
+            r::morton_decode(a, b),
+            "{a:?} {b:?}"
+        );
+    }
+}
+
+#[test]
+pub fn i8x8_binary() {
+    use reference::i8x8 as r;
+    for (a, b) in sample_pairs() {
+        let (a, b) = (a.cast_i8x8(), b.cast_i8x8());
+        assert_eq!(a.equals(b), r::equals(a, b), "{a:?} {b:?}");
+        assert_eq!(a.equals(a), r::equals(a, a), "{a:?}");
+        assert_eq!(a.less_than(b), r::less_than(a, b), "{a:?} {b:?}");
+        assert_eq!(a.greater_than(b), r::greater_than(a, b), "{a:?} {b:?}");
+    }
+}
+
+/// Joins two [`u8x8`] samples into a [`u8x16`].
+fn join(a: u8x8, b: u8x8) -> u8x16 {
+    let mut r = [0; 16];
+    r[..8].copy_from_slice(&a.to_array());
+    r[8..].copy_from_slice(&b.to_array());
+    u8x16::from_array(r)
+}
+
+/// Joins two [`mask8x8`] samples into a [`mask8x16`].
+fn join_masks(a: mask8x8, b: mask8x8) -> mask8x16 {
+    let mut r = [false; 16];
+    r[..8].copy_from_slice(&a.to_array());
+    r[8..].copy_from_slice(&b.to_array());
+    mask8x16::from_array(r)
+}
+
+#[test]
+pub fn u8x16_binary() {
+    // The u8x8 operations are already checked against the reference
+    // implementations above, so each half of a u8x16 result must match
+    // the result of the corresponding u8x8 operation.
+    let pairs: Vec<_> = sample_pairs().collect();
+    for (i, &(a0, b0)) in pairs.iter().enumerate() {
+        let (a1, b1) = pairs[(i * 7 + 3) % pairs.len()];
+        let (a, b) = (join(a0, a1), join(b0, b1));
+        let ctx = format!("{a:?} {b:?}");
+        assert_eq!(
+            a.complement(),
+            join(a0.complement(), a1.complement()),
+            "{ctx}"
+        );
+        assert_eq!(a.popcount(), join(a0.popcount(), a1.popcount()), "{ctx}");
+        assert_eq!(a.reduce_sum(), a0.reduce_sum() + a1.reduce_sum(), "{ctx}");
+        assert_eq!(
+            a.equals(b),
+            join_masks(a0.equals(b0), a1.equals(b1)),
+            "{ctx}"
+        );
+        assert_eq!(
+            a.less_than(b),
+            join_masks(a0.less_than(b0), a1.less_than(b1)),
+            "{ctx}"
+        );
+        assert_eq!(
+            a.greater_than(b),
+            join_masks(a0.greater_than(b0), a1.greater_than(b1)),
+            "{ctx}"
+        );
+        macro_rules! check {
+            ($($m:ident),*) => {
+                $(assert_eq!(a.$m(b), join(a0.$m(b0), a1.$m(b1)), "{} {ctx}", stringify!($m));)*
+            };
+        }
+        check!(
+            bitor,
+            bitand,
+            bitxor,
+            wrapping_add,
+            saturating_add,
+            wrapping_sub,
+            saturating_sub,
+            abs_difference,
+            max,
+            min,
+            mean,
+            rounding_mean
+        );
+    }
+}
+
+#[test]
+pub fn mask8x16_conversions() {
+    let masks: Vec<_> = sample_masks().collect();
+    for (i, &m0) in masks.iter().enumerate() {
+        let m1 = masks[(i * 5 + 1) % masks.len()];
+        let m = join_masks(m0, m1);
+        let le = m0.to_bitmask_le() as u16 | (m1.to_bitmask_le() as u16) << 8;
+        assert_eq!(m.to_bitmask_le(), le, "{m:?}");
+        assert_eq!(mask8x16::from_bitmask_le(le), m, "{m:?}");
+        let be = (m0.to_bitmask_be() as u16) << 8 | m1.to_bitmask_be() as u16;
+        assert_eq!(m.to_bitmask_be(), be, "{m:?}");
+        assert_eq!(mask8x16::from_bitmask_be(be), m, "{m:?}");
+        assert_eq!(m.count_true(), m0.count_true() + m1.count_true(), "{m:?}");
+        assert_eq!(
+            m.select(3, 200),
+            join(m0.select(3, 200), m1.select(3, 200)),
+            "{m:?}"
+        );
+    }
+}
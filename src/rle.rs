@@ -0,0 +1,158 @@
+//! Run-length encoding and decoding of byte slices.
+//!
+//! The encoded form is a sequence of pairs of bytes where the first byte of
+//! each pair is a run length between 1 and 255 inclusive, and the second is
+//! the byte value repeated throughout that run. Runs longer than 255 bytes
+//! are split into multiple pairs.
+//!
+//! The encoder finds runs eight bytes at a time by comparing each following
+//! group of eight bytes with the current run's value.
+//!
+//! ```rust
+//! # use eight_bytes::rle;
+//! let src = b"aaaaaaaaaaaabccc";
+//! let mut encoded = [0_u8; 32];
+//! let len = rle::encode(src, &mut encoded).unwrap();
+//! assert_eq!(&encoded[..len], &[12, b'a', 1, b'b', 3, b'c']);
+//!
+//! let mut decoded = [0_u8; 32];
+//! let len = rle::decode(&encoded[..len], &mut decoded).unwrap();
+//! assert_eq!(&decoded[..len], src);
+//! ```
+
+use crate::{mask8x8, u8x8};
+
+/// The longest run that can be represented in a single pair.
+const MAX_RUN: usize = 255;
+
+/// Errors returned by the functions in this module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The destination buffer is too small to hold the result.
+    BufferTooSmall,
+    /// The encoded input ends partway through a pair.
+    Truncated,
+    /// The encoded input contains a run of length zero.
+    ZeroLengthRun,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::BufferTooSmall => f.write_str("destination buffer too small"),
+            Error::Truncated => f.write_str("encoded input is truncated"),
+            Error::ZeroLengthRun => f.write_str("encoded input contains a zero-length run"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Returns the maximum number of bytes that [`encode`] could write when
+/// encoding a source slice of the given length.
+#[inline(always)]
+pub const fn max_encoded_len(src_len: usize) -> usize {
+    src_len * 2
+}
+
+/// Run-length-encodes `src` into `dst`, returning the number of bytes
+/// written to `dst`.
+///
+/// Returns [`Error::BufferTooSmall`] if `dst` is not long enough to hold
+/// the encoded result, in which case the content of `dst` is unspecified.
+/// A `dst` of at least [`max_encoded_len`] bytes is always sufficient.
+pub fn encode(src: &[u8], dst: &mut [u8]) -> Result<usize, Error> {
+    let mut remain = src;
+    let mut written = 0;
+    while let Some(&v) = remain.first() {
+        let len = run_len(remain);
+        let Some(pair) = dst.get_mut(written..written + 2) else {
+            return Err(Error::BufferTooSmall);
+        };
+        pair[0] = len as u8;
+        pair[1] = v;
+        written += 2;
+        remain = &remain[len..];
+    }
+    Ok(written)
+}
+
+/// Decodes the run-length-encoded `src` into `dst`, returning the number
+/// of bytes written to `dst`.
+///
+/// Returns an error if `src` is not valid or if `dst` is not long enough
+/// to hold the decoded result. The content of `dst` is unspecified after
+/// an error.
+pub fn decode(src: &[u8], dst: &mut [u8]) -> Result<usize, Error> {
+    let mut written = 0;
+    for pair in pairs(src)? {
+        let (len, v) = pair?;
+        let Some(run) = dst.get_mut(written..written + len) else {
+            return Err(Error::BufferTooSmall);
+        };
+        run.fill(v);
+        written += len;
+    }
+    Ok(written)
+}
+
+/// Run-length-encodes `src` into a newly-allocated vector.
+#[cfg(feature = "alloc")]
+pub fn encode_to_vec(src: &[u8]) -> alloc::vec::Vec<u8> {
+    let mut ret = alloc::vec::Vec::new();
+    let mut remain = src;
+    while let Some(&v) = remain.first() {
+        let len = run_len(remain);
+        ret.push(len as u8);
+        ret.push(v);
+        remain = &remain[len..];
+    }
+    ret
+}
+
+/// Decodes the run-length-encoded `src` into a newly-allocated vector.
+///
+/// Returns an error if `src` is not valid. This function never returns
+/// [`Error::BufferTooSmall`].
+#[cfg(feature = "alloc")]
+pub fn decode_to_vec(src: &[u8]) -> Result<alloc::vec::Vec<u8>, Error> {
+    let mut ret = alloc::vec::Vec::new();
+    for pair in pairs(src)? {
+        let (len, v) = pair?;
+        ret.resize(ret.len() + len, v);
+    }
+    Ok(ret)
+}
+
+/// Returns the length of the run at the start of `s`, which must not be
+/// empty, capped at [`MAX_RUN`].
+fn run_len(s: &[u8]) -> usize {
+    let limit = core::cmp::min(s.len(), MAX_RUN);
+    let want = u8x8::splat(s[0]);
+    let mut len = 1;
+    while len + 8 <= limit {
+        let chunk: [u8; 8] = s[len..len + 8].try_into().unwrap();
+        let same = u8x8::from_array(chunk).equals(want);
+        if same != mask8x8::ALL_TRUE {
+            // The run ends at the first element that isn't equal.
+            return len + same.not().to_bitmask_le().trailing_zeros() as usize;
+        }
+        len += 8;
+    }
+    while len < limit && s[len] == s[0] {
+        len += 1;
+    }
+    len
+}
+
+/// Returns an iterator over the (length, value) pairs in `src`, or an error
+/// if `src` does not have an even length.
+fn pairs(src: &[u8]) -> Result<impl Iterator<Item = Result<(usize, u8), Error>> + '_, Error> {
+    if !src.len().is_multiple_of(2) {
+        return Err(Error::Truncated);
+    }
+    Ok(src.chunks_exact(2).map(|pair| match pair[0] {
+        0 => Err(Error::ZeroLengthRun),
+        len => Ok((len as usize, pair[1])),
+    }))
+}
@@ -0,0 +1,67 @@
+use super::*;
+
+fn sample_bytes() -> [u8; 600] {
+    let mut ret = [0_u8; 600];
+    ret[3..20].fill(1);
+    ret[20] = 2;
+    ret[21..30].fill(3);
+    ret[30..31].fill(4);
+    ret[31..600].fill(5);
+    ret
+}
+
+#[test]
+pub fn encode() {
+    let src = sample_bytes();
+    let mut dst = [0_u8; 32];
+    let len = rle::encode(&src, &mut dst).unwrap();
+    let want = [
+        3, 0, 17, 1, 1, 2, 9, 3, 1, 4, 255, 5, 255, 5, 59, 5, //
+    ];
+    assert_eq!(&dst[..len], &want);
+}
+
+#[test]
+pub fn encode_buffer_too_small() {
+    let src = sample_bytes();
+    let mut dst = [0_u8; 15];
+    assert_eq!(rle::encode(&src, &mut dst), Err(rle::Error::BufferTooSmall));
+}
+
+#[test]
+pub fn decode() {
+    let src = sample_bytes();
+    let mut encoded = [0_u8; 32];
+    let len = rle::encode(&src, &mut encoded).unwrap();
+    let mut dst = [0_u8; 600];
+    let len = rle::decode(&encoded[..len], &mut dst).unwrap();
+    assert_eq!(len, 600);
+    assert_eq!(dst, src);
+}
+
+#[test]
+pub fn decode_errors() {
+    let mut dst = [0_u8; 4];
+    assert_eq!(
+        rle::decode(&[2, 1, 3], &mut dst),
+        Err(rle::Error::Truncated)
+    );
+    assert_eq!(
+        rle::decode(&[2, 1, 0, 3], &mut dst),
+        Err(rle::Error::ZeroLengthRun)
+    );
+    assert_eq!(
+        rle::decode(&[2, 1, 3, 3], &mut dst),
+        Err(rle::Error::BufferTooSmall)
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+pub fn encode_decode_vec() {
+    let src = sample_bytes();
+    let encoded = rle::encode_to_vec(&src);
+    assert_eq!(encoded.len(), 16);
+    let decoded = rle::decode_to_vec(&encoded).unwrap();
+    assert_eq!(decoded, src);
+}
@@ -0,0 +1,95 @@
+//! Sorting very small byte slices using sorting networks applied across
+//! whole [`u8x8`] vectors at once.
+//!
+//! ```rust
+//! # use eight_bytes::sort;
+//! let mut keys = *b"the quick brown fox";
+//! sort::sort_small(&mut keys);
+//! assert_eq!(&keys, b"   bcefhiknooqrtuwx");
+//! ```
+
+use crate::bitslice::transpose_elements;
+use crate::u8x8;
+
+/// The largest slice length accepted by [`sort_small`].
+pub const MAX_SMALL_LEN: usize = 64;
+
+/// Sorts the given slice of at most [`MAX_SMALL_LEN`] bytes in ascending
+/// order, without using any memory beyond a fixed-size buffer on the stack.
+///
+/// The bytes are treated as an 8x8 matrix, which is sorted using shearsort:
+/// sorting networks run on all eight columns at once, and transposing the
+/// matrix lets the same networks sort all eight rows at once.
+///
+/// # Panics
+///
+/// Panics if `buf` is longer than [`MAX_SMALL_LEN`].
+pub fn sort_small(buf: &mut [u8]) {
+    assert!(
+        buf.len() <= MAX_SMALL_LEN,
+        "sort_small supports at most {MAX_SMALL_LEN} bytes"
+    );
+    // Padding with the maximum value means it all sorts to the end, after
+    // the bytes we're going to copy back.
+    let mut bytes = [0xff_u8; MAX_SMALL_LEN];
+    bytes[..buf.len()].copy_from_slice(buf);
+    let mut rows = [u8x8::ZEROES; 8];
+    for (row, chunk) in rows.iter_mut().zip(bytes.chunks_exact(8)) {
+        *row = u8x8::from_array(chunk.try_into().unwrap());
+    }
+
+    // log2(8) + 1 rounds are enough for an 8x8 matrix to end up sorted in
+    // boustrophedon order, with odd rows descending.
+    const ODD: u64 = u8x8::from_array([0, 0xff, 0, 0xff, 0, 0xff, 0, 0xff]).n;
+    for _ in 0..4 {
+        rows = transpose_elements(sort_columns(transpose_elements(rows), ODD));
+        rows = sort_columns(rows, 0);
+    }
+    // Each row now contains only values no greater than those of the
+    // following row, so sorting each row ascending completes the sort.
+    rows = transpose_elements(sort_columns(transpose_elements(rows), 0));
+
+    for (row, chunk) in rows.iter().zip(bytes.chunks_exact_mut(8)) {
+        chunk.copy_from_slice(&row.to_array());
+    }
+    let len = buf.len();
+    buf.copy_from_slice(&bytes[..len]);
+}
+
+/// An optimal sorting network for eight inputs, as pairs of indices to
+/// compare and exchange.
+const NETWORK: [(usize, usize); 19] = [
+    (0, 2),
+    (1, 3),
+    (4, 6),
+    (5, 7),
+    (0, 4),
+    (1, 5),
+    (2, 6),
+    (3, 7),
+    (0, 1),
+    (2, 3),
+    (4, 5),
+    (6, 7),
+    (2, 4),
+    (3, 5),
+    (1, 4),
+    (3, 6),
+    (1, 2),
+    (3, 4),
+    (5, 6),
+];
+
+/// Sorts each column of the matrix whose rows are `rows`, ascending from
+/// the first row to the last except in the columns whose elements are set
+/// in `descending`, which sort in the opposite direction.
+#[inline(always)]
+fn sort_columns(mut rows: [u8x8; 8], descending: u64) -> [u8x8; 8] {
+    for (i, j) in NETWORK {
+        let lo = rows[i].min(rows[j]);
+        let hi = rows[i].max(rows[j]);
+        rows[i] = u8x8::new((lo.n & !descending) | (hi.n & descending));
+        rows[j] = u8x8::new((hi.n & !descending) | (lo.n & descending));
+    }
+    rows
+}
@@ -0,0 +1,32 @@
+use super::*;
+
+#[test]
+pub fn sort_small() {
+    let mut state = 0x2545f4914f6cdd1d_u64;
+    for len in 0..=sort::MAX_SMALL_LEN {
+        for round in 0..20 {
+            let mut buf = [0_u8; sort::MAX_SMALL_LEN];
+            for b in buf[..len].iter_mut() {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                // Alternate between the full range and a few distinct values,
+                // so that there are plenty of duplicates and 0xff elements.
+                *b = match round % 2 {
+                    0 => state as u8,
+                    _ => [0, 1, 0xfe, 0xff][(state & 3) as usize],
+                };
+            }
+            let mut want = buf;
+            want[..len].sort_unstable();
+            sort::sort_small(&mut buf[..len]);
+            assert_eq!(buf, want, "length {len}");
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+pub fn sort_small_too_long() {
+    sort::sort_small(&mut [0; 65]);
+}
@@ -0,0 +1,132 @@
+//! Aggregate statistics over byte slices and collections of byte slices.
+//!
+//! Most of the functions in this module treat a slice of equal-length rows
+//! as a two-dimensional array and aggregate each column, which is the basis
+//! of techniques such as background subtraction over a series of frames or
+//! fusing readings from several sensor channels. [`sum_bytes`] instead
+//! aggregates all of the bytes in a single slice.
+//!
+//! ```rust
+//! # use eight_bytes::stats;
+//! let rows: [&[u8]; 3] = [&[1, 50, 9], &[4, 20, 9], &[2, 80, 0]];
+//! let mut max = [0_u8; 3];
+//! stats::column_max(&rows, &mut max);
+//! assert_eq!(max, [4, 80, 9]);
+//! let mut sum = [0_u32; 3];
+//! stats::column_sum(&rows, &mut sum);
+//! assert_eq!(sum, [7, 150, 18]);
+//! ```
+
+use crate::u8x8;
+
+/// Sets each element of `dst` to the minimum of the corresponding elements
+/// of all of the given rows, or to 255 if there are no rows.
+///
+/// # Panics
+///
+/// Panics if any row has a different length than `dst`.
+pub fn column_min(rows: &[&[u8]], dst: &mut [u8]) {
+    fold_columns(rows, dst, u8x8::splat(255), u8x8::min);
+}
+
+/// Sets each element of `dst` to the maximum of the corresponding elements
+/// of all of the given rows, or to zero if there are no rows.
+///
+/// # Panics
+///
+/// Panics if any row has a different length than `dst`.
+pub fn column_max(rows: &[&[u8]], dst: &mut [u8]) {
+    fold_columns(rows, dst, u8x8::ZEROES, u8x8::max);
+}
+
+/// Sets each element of `dst` to the sum of the corresponding elements of
+/// all of the given rows.
+///
+/// The sums are accumulated in 16-bit lanes for as many rows as possible
+/// before being added into `dst`, so this is considerably faster than
+/// widening every element individually. Sums of more than 16,843,009 rows
+/// may overflow `u32`.
+///
+/// # Panics
+///
+/// Panics if any row has a different length than `dst`.
+pub fn column_sum(rows: &[&[u8]], dst: &mut [u32]) {
+    const LOW_BYTES: u64 = 0x00ff00ff00ff00ff;
+    // The most rows of 255 that a 16-bit lane can accumulate.
+    const BATCH: usize = 257;
+
+    check_lengths(rows, dst.len());
+    dst.fill(0);
+    for (k, dst) in dst.chunks_mut(8).enumerate() {
+        for batch in rows.chunks(BATCH) {
+            let (mut even, mut odd) = (0_u64, 0_u64);
+            for row in batch {
+                let n = load(row, k * 8).n.to_le();
+                even += n & LOW_BYTES;
+                odd += (n >> 8) & LOW_BYTES;
+            }
+            for (i, sum) in dst.iter_mut().enumerate() {
+                let lanes = if i % 2 == 0 { even } else { odd };
+                *sum += ((lanes >> ((i / 2) * 16)) & 0xffff) as u32;
+            }
+        }
+    }
+}
+
+/// Returns the sum of all of the bytes in `data`.
+///
+/// The bytes are accumulated eight at a time in 16-bit lanes for as long as
+/// those lanes cannot overflow, and only then widened into the total. The
+/// result is exact for any slice length, because a `u64` can hold the sum
+/// of more bytes than can exist in memory.
+///
+/// ```rust
+/// # use eight_bytes::stats;
+/// let data = vec![255_u8; 1_000_000];
+/// assert_eq!(stats::sum_bytes(&data), 255_000_000);
+/// ```
+pub fn sum_bytes(data: &[u8]) -> u64 {
+    const LOW_U16S: u64 = 0x0000ffff0000ffff;
+    // Each chunk adds at most 2*255 to each 16-bit lane, so this is the
+    // most chunks that a 16-bit lane can accumulate.
+    const BATCH: usize = 128;
+
+    let (start, middle, end) = u8x8::from_byte_slice(data);
+    let mut total: u64 = start.iter().chain(end).map(|b| *b as u64).sum();
+    for batch in middle.chunks(BATCH) {
+        let mut lanes = 0_u64;
+        for v in batch {
+            lanes += v.pairwise_add().n;
+        }
+        let lanes = (lanes & LOW_U16S) + ((lanes >> 16) & LOW_U16S);
+        total += (lanes & 0xffffffff) + (lanes >> 32);
+    }
+    total
+}
+
+/// Sets each group of eight elements in `dst` to the result of combining
+/// `init` with the corresponding elements of each row in turn using `f`.
+#[inline(always)]
+fn fold_columns(rows: &[&[u8]], dst: &mut [u8], init: u8x8, f: impl Fn(u8x8, u8x8) -> u8x8) {
+    check_lengths(rows, dst.len());
+    for (k, dst) in dst.chunks_mut(8).enumerate() {
+        let acc = rows.iter().fold(init, |acc, row| f(acc, load(row, k * 8)));
+        dst.copy_from_slice(&acc.to_array()[..dst.len()]);
+    }
+}
+
+/// Returns up to eight bytes of `row` starting at `start`, zero-padded.
+#[inline(always)]
+fn load(row: &[u8], start: usize) -> u8x8 {
+    let src = &row[start..core::cmp::min(start + 8, row.len())];
+    let mut a = [0_u8; 8];
+    a[..src.len()].copy_from_slice(src);
+    u8x8::from_array(a)
+}
+
+fn check_lengths(rows: &[&[u8]], len: usize) {
+    assert!(
+        rows.iter().all(|row| row.len() == len),
+        "all rows must have the same length as the destination"
+    );
+}
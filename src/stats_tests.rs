@@ -0,0 +1,69 @@
+use super::*;
+
+fn sample_rows() -> Vec<Vec<u8>> {
+    (0..600)
+        .map(|r| {
+            (0..19)
+                .map(|c| {
+                    if r % 3 == 0 {
+                        255
+                    } else {
+                        ((r * 7 + c * 13) % 256) as u8
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+pub fn column_min() {
+    let rows = sample_rows();
+    let rows: Vec<&[u8]> = rows.iter().map(|r| &r[..]).collect();
+    let mut got = [0_u8; 19];
+    stats::column_min(&rows[1..], &mut got);
+    let want: [u8; 19] = core::array::from_fn(|c| rows[1..].iter().map(|r| r[c]).min().unwrap());
+    assert_eq!(got, want);
+
+    stats::column_min(&[], &mut got);
+    assert_eq!(got, [255; 19]);
+}
+
+#[test]
+pub fn column_max() {
+    let rows = sample_rows();
+    let rows: Vec<&[u8]> = rows.iter().map(|r| &r[..]).collect();
+    let mut got = [0_u8; 19];
+    stats::column_max(&rows[1..3], &mut got);
+    let want: [u8; 19] = core::array::from_fn(|c| rows[1..3].iter().map(|r| r[c]).max().unwrap());
+    assert_eq!(got, want);
+
+    stats::column_max(&[], &mut got);
+    assert_eq!(got, [0; 19]);
+}
+
+#[test]
+pub fn column_sum() {
+    let rows = sample_rows();
+    let rows: Vec<&[u8]> = rows.iter().map(|r| &r[..]).collect();
+    let mut got = [0_u32; 19];
+    stats::column_sum(&rows, &mut got);
+    let want: [u32; 19] = core::array::from_fn(|c| rows.iter().map(|r| r[c] as u32).sum());
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn sum_bytes() {
+    let rows = sample_rows();
+    let data: Vec<u8> = rows.concat();
+    // Every possible alignment and length near the batch boundaries should
+    // agree with the scalar sum.
+    for start in 0..9 {
+        for end in [start, start + 7, 1024, 1031, 2048, 4096, data.len()] {
+            let data = &data[start..end];
+            let want: u64 = data.iter().map(|b| *b as u64).sum();
+            assert_eq!(stats::sum_bytes(data), want, "{start}..{end}");
+        }
+    }
+    assert_eq!(stats::sum_bytes(&[255; 4099]), 255 * 4099);
+}
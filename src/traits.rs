@@ -0,0 +1,190 @@
+use crate::{mask8x8, mask8x16, mask16x4, mask32x2, u8x8, u8x16, u16x4};
+
+/// Operations shared by all of the vector types in this crate, so that
+/// algorithms can be written once and then used with any lane width.
+///
+/// ```rust
+/// # use eight_bytes::{SwarMask, SwarVector, u8x8, u8x16};
+/// /// Counts the elements of `haystack` equal to `needle`, ignoring any
+/// /// trailing elements that don't fill a whole vector.
+/// fn count<V: SwarVector>(haystack: &[V::Array], needle: V::Lane) -> u32 {
+///     let needle = V::splat(needle);
+///     haystack
+///         .iter()
+///         .map(|a| V::from_array(*a).equals(needle).count_true())
+///         .sum()
+/// }
+/// let data = *b"abcabcabcabcabca";
+/// assert_eq!(count::<u8x8>(&[data[..8].try_into().unwrap()], b'a'), 3);
+/// assert_eq!(count::<u8x16>(&[data], b'a'), 6);
+/// ```
+pub trait SwarVector: Copy + Eq + core::fmt::Debug {
+    /// The type of each element of the vector.
+    type Lane: Copy + Eq + core::fmt::Debug;
+
+    /// An array of [`Self::LANES`] elements of type [`Self::Lane`].
+    type Array: Copy + AsRef<[Self::Lane]> + AsMut<[Self::Lane]>;
+
+    /// The mask type returned by the comparison methods.
+    type Mask: SwarMask;
+
+    /// The number of elements in the vector.
+    const LANES: usize;
+
+    /// A vector where all elements are set to zero.
+    const ZEROES: Self;
+
+    /// Returns a vector with `v` in all of its elements.
+    fn splat(v: Self::Lane) -> Self;
+
+    /// Converts an array of elements into a vector.
+    fn from_array(a: Self::Array) -> Self;
+
+    /// Converts the vector into an array of elements.
+    fn to_array(self) -> Self::Array;
+
+    /// Implements addition across corresponding elements, wrapping around
+    /// on overflow.
+    fn wrapping_add(self, other: Self) -> Self;
+
+    /// Implements subtraction across corresponding elements, wrapping around
+    /// on overflow.
+    fn wrapping_sub(self, other: Self) -> Self;
+
+    /// Returns a mask that is `true` for each element that is equal in both
+    /// vectors.
+    fn equals(self, other: Self) -> Self::Mask;
+
+    /// Returns a mask that is `true` for each element of `self` that is less
+    /// than the corresponding element of `other`.
+    fn less_than(self, other: Self) -> Self::Mask;
+
+    /// Returns a mask that is `true` for each element of `self` that is
+    /// greater than the corresponding element of `other`.
+    fn greater_than(self, other: Self) -> Self::Mask;
+}
+
+/// Operations shared by all of the mask types in this crate, which are
+/// returned by the comparison methods of [`SwarVector`].
+pub trait SwarMask: Copy + Eq + core::fmt::Debug {
+    /// The number of elements in the mask.
+    const LANES: usize;
+
+    /// A mask where all elements are set to `false`.
+    const ALL_FALSE: Self;
+
+    /// A mask where all elements are set to `true`.
+    const ALL_TRUE: Self;
+
+    /// Computes the complement of each element in the mask.
+    fn not(self) -> Self;
+
+    /// Computes a logical OR result for each element across both masks.
+    fn or(self, other: Self) -> Self;
+
+    /// Computes a logical AND result for each element across both masks.
+    fn and(self, other: Self) -> Self;
+
+    /// Converts the mask into a bitmask where the first element is in the
+    /// least significant bit.
+    fn to_bitmask_le(self) -> u64;
+
+    /// Returns the number of elements in the mask that are set to `true`.
+    fn count_true(self) -> u32;
+}
+
+macro_rules! impl_swar_vector {
+    ($t:ident, $lane:ty, $lanes:literal, $mask:ident) => {
+        impl SwarVector for $t {
+            type Lane = $lane;
+            type Array = [$lane; $lanes];
+            type Mask = $mask;
+
+            const LANES: usize = $lanes;
+            const ZEROES: Self = $t::ZEROES;
+
+            #[inline(always)]
+            fn splat(v: $lane) -> Self {
+                $t::splat(v)
+            }
+
+            #[inline(always)]
+            fn from_array(a: [$lane; $lanes]) -> Self {
+                $t::from_array(a)
+            }
+
+            #[inline(always)]
+            fn to_array(self) -> [$lane; $lanes] {
+                $t::to_array(self)
+            }
+
+            #[inline(always)]
+            fn wrapping_add(self, other: Self) -> Self {
+                $t::wrapping_add(self, other)
+            }
+
+            #[inline(always)]
+            fn wrapping_sub(self, other: Self) -> Self {
+                $t::wrapping_sub(self, other)
+            }
+
+            #[inline(always)]
+            fn equals(self, other: Self) -> $mask {
+                $t::equals(self, other)
+            }
+
+            #[inline(always)]
+            fn less_than(self, other: Self) -> $mask {
+                $t::less_than(self, other)
+            }
+
+            #[inline(always)]
+            fn greater_than(self, other: Self) -> $mask {
+                $t::greater_than(self, other)
+            }
+        }
+    };
+}
+
+macro_rules! impl_swar_mask {
+    ($t:ident, $lanes:literal) => {
+        impl SwarMask for $t {
+            const LANES: usize = $lanes;
+            const ALL_FALSE: Self = $t::ALL_FALSE;
+            const ALL_TRUE: Self = $t::ALL_TRUE;
+
+            #[inline(always)]
+            fn not(self) -> Self {
+                $t::not(self)
+            }
+
+            #[inline(always)]
+            fn or(self, other: Self) -> Self {
+                $t::or(self, other)
+            }
+
+            #[inline(always)]
+            fn and(self, other: Self) -> Self {
+                $t::and(self, other)
+            }
+
+            #[inline(always)]
+            fn to_bitmask_le(self) -> u64 {
+                $t::to_bitmask_le(self) as u64
+            }
+
+            #[inline(always)]
+            fn count_true(self) -> u32 {
+                $t::count_true(self)
+            }
+        }
+    };
+}
+
+impl_swar_vector!(u8x8, u8, 8, mask8x8);
+impl_swar_vector!(u8x16, u8, 16, mask8x16);
+impl_swar_vector!(u16x4, u16, 4, mask16x4);
+impl_swar_mask!(mask8x8, 8);
+impl_swar_mask!(mask8x16, 16);
+impl_swar_mask!(mask16x4, 4);
+impl_swar_mask!(mask32x2, 2);
@@ -0,0 +1,49 @@
+use super::*;
+
+/// Returns the index of the first element of `a` that is less than the
+/// corresponding element of `b`, using only the generic traits.
+fn first_less<V: SwarVector>(a: V::Array, b: V::Array) -> Option<usize> {
+    let mask = V::from_array(a).less_than(V::from_array(b));
+    match mask.to_bitmask_le() {
+        0 => None,
+        bits => Some(bits.trailing_zeros() as usize),
+    }
+}
+
+fn roundtrip<V: SwarVector>(a: V::Array, one: V::Lane) {
+    let v = V::from_array(a);
+    assert_eq!(v.to_array().as_ref(), a.as_ref());
+    let inc = v.wrapping_add(V::splat(one));
+    assert_eq!(inc.wrapping_sub(V::splat(one)), v);
+    assert_eq!(v.equals(v), V::Mask::ALL_TRUE);
+    assert_eq!(v.equals(inc), V::Mask::ALL_FALSE);
+    assert_eq!(v.equals(v).count_true() as usize, V::LANES);
+    assert_eq!(V::Mask::ALL_TRUE.not(), V::Mask::ALL_FALSE);
+    assert_eq!(V::ZEROES.to_array().as_ref().len(), V::LANES);
+}
+
+#[test]
+pub fn generic_u8x8() {
+    roundtrip::<u8x8>([1, 2, 3, 4, 5, 6, 7, 255], 1);
+    let a = [5, 5, 5, 5, 5, 5, 5, 5];
+    assert_eq!(first_less::<u8x8>(a, [0, 0, 0, 9, 0, 9, 0, 0]), Some(3));
+    assert_eq!(first_less::<u8x8>(a, a), None);
+}
+
+#[test]
+pub fn generic_u8x16() {
+    let a: [u8; 16] = core::array::from_fn(|i| i as u8 * 16);
+    roundtrip::<u8x16>(a, 1);
+    let mut b = [0; 16];
+    b[12] = 255;
+    assert_eq!(first_less::<u8x16>(a, b), Some(12));
+    assert_eq!(<u8x16 as SwarVector>::Mask::LANES, 16);
+}
+
+#[test]
+pub fn generic_u16x4() {
+    roundtrip::<u16x4>([1, 0x100, 0x7fff, 0xffff], 1);
+    let a = [0x8000, 0x8000, 0x8000, 0x8000];
+    assert_eq!(first_less::<u16x4>(a, [0x7fff, 0, 0x8001, 0]), Some(2));
+    assert_eq!(<mask32x2 as SwarMask>::ALL_TRUE.count_true(), 2);
+}
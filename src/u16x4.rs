@@ -0,0 +1,293 @@
+use crate::vecmod::{gather_bytes, mul_lanes_16, spread_bytes};
+use crate::{mask16x4, u8x8};
+
+/// A vector of four `u16` values, which can have SIMD-like operations
+/// applied to them without any explicit SIMD instructions.
+///
+/// This type is really just a [`u64`], but its methods interpret it as four
+/// [`u16`] values where the same operation is applied to all four values at
+/// once. It is primarily useful as an intermediate representation for
+/// arithmetic on [`u8x8`] values that would otherwise overflow.
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct u16x4 {
+    pub(crate) n: u64,
+}
+
+impl u16x4 {
+    /// A [`u16x4`] value where all four elements are set to zero.
+    pub const ZEROES: Self = Self::new(0);
+
+    /// Converts an array of four `u16` values into a [`u16x4`] value.
+    #[inline(always)]
+    pub const fn from_array(a: [u16; 4]) -> Self {
+        // Safety: [u16; 4] and u64 have the same size, and all bit
+        // patterns are valid for both.
+        Self {
+            n: unsafe { core::mem::transmute::<[u16; 4], u64>(a) },
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) const fn new(n: u64) -> Self {
+        Self { n }
+    }
+
+    /// Returns a [`u16x4`] with `v` in all four of its elements.
+    #[inline(always)]
+    pub const fn splat(v: u16) -> Self {
+        Self::new(v as u64 * ALL_ONES_16)
+    }
+
+    /// Converts the vector into an array of four `u16` values.
+    #[inline(always)]
+    pub const fn to_array(self) -> [u16; 4] {
+        // Safety: [u16; 4] and u64 have the same size, and all bit
+        // patterns are valid for both.
+        unsafe { core::mem::transmute::<u64, [u16; 4]>(self.n) }
+    }
+
+    /// Returns the raw value with the elements arranged so that element `i`
+    /// occupies bits `16*i` through `16*i+15`, regardless of the target's
+    /// byte order.
+    #[inline(always)]
+    pub(crate) const fn to_le_lanes(self) -> u64 {
+        if cfg!(target_endian = "big") {
+            reverse_lanes(self.n)
+        } else {
+            self.n
+        }
+    }
+
+    /// The inverse of [`Self::to_le_lanes`].
+    #[inline(always)]
+    pub(crate) const fn from_le_lanes(n: u64) -> Self {
+        if cfg!(target_endian = "big") {
+            Self::new(reverse_lanes(n))
+        } else {
+            Self::new(n)
+        }
+    }
+
+    /// Compares each element across both vectors and returns a mask value
+    /// where `true` represents equality and `false` represents inequality.
+    #[inline(always)]
+    pub const fn equals(self, other: Self) -> mask16x4 {
+        let xo = self.n ^ other.n;
+        let lo = ((xo & WITHOUT_HIGH_BITS_16) + WITHOUT_HIGH_BITS_16) | xo;
+        let hi = !lo & ONLY_HIGH_BITS_16;
+        mask16x4::new(hi >> 15)
+    }
+
+    /// Compares each element across both vectors and returns a mask value
+    /// with elements set to `true` where the corresponding element in `self`
+    /// is less than the corresponding element in `other`.
+    #[inline(always)]
+    pub const fn less_than(self, other: Self) -> mask16x4 {
+        let diff = (self.n | ONLY_HIGH_BITS_16).wrapping_sub(other.n & !ONLY_HIGH_BITS_16);
+        let select =
+            ((self.n & (self.n ^ other.n)) | (diff & !(self.n ^ other.n))) & ONLY_HIGH_BITS_16;
+        let inv = select ^ ONLY_HIGH_BITS_16;
+        mask16x4::new(inv >> 15)
+    }
+
+    /// Compares each element across both vectors and returns a mask value
+    /// with elements set to `true` where the corresponding element in `self`
+    /// is greater than the corresponding element in `other`.
+    #[inline(always)]
+    pub const fn greater_than(self, other: Self) -> mask16x4 {
+        other.less_than(self)
+    }
+
+    /// Implements addition across corresponding elements, modulo 65536.
+    #[inline(always)]
+    pub const fn wrapping_add(self, other: Self) -> Self {
+        let low = (self.n & WITHOUT_HIGH_BITS_16).wrapping_add(other.n & WITHOUT_HIGH_BITS_16);
+        Self::new(low ^ ((self.n ^ other.n) & ONLY_HIGH_BITS_16))
+    }
+
+    /// Implements subtraction across corresponding elements, modulo 65536.
+    #[inline(always)]
+    pub const fn wrapping_sub(self, other: Self) -> Self {
+        Self::new(
+            (self.n | ONLY_HIGH_BITS_16).wrapping_sub(other.n & WITHOUT_HIGH_BITS_16)
+                ^ ((self.n ^ !other.n) & ONLY_HIGH_BITS_16),
+        )
+    }
+
+    /// Narrows the elements of both vectors to `u8`, saturating at 255, and
+    /// returns a [`u8x8`] whose first four elements come from `lo` and whose
+    /// last four elements come from `hi`.
+    ///
+    /// This is the inverse of [`u8x8::widen`] for values that fit in `u8`.
+    ///
+    /// ```rust
+    /// # use eight_bytes::{u8x8, u16x4};
+    /// let lo = u16x4::from_array([1, 255, 256, 1000]);
+    /// let hi = u16x4::from_array([0, 7, 65535, 128]);
+    /// let v = u16x4::pack_saturating(lo, hi);
+    /// assert_eq!(v.to_array(), [1, 255, 255, 255, 0, 7, 255, 128]);
+    /// ```
+    #[inline(always)]
+    pub const fn pack_saturating(lo: Self, hi: Self) -> u8x8 {
+        let lo = Self::narrow_saturating_le(lo.to_le_lanes());
+        let hi = Self::narrow_saturating_le(hi.to_le_lanes());
+        u8x8::new(u64::from_le(lo | (hi << 32)))
+    }
+
+    /// Separates the bits of each 16-bit Morton code in `lo` and `hi` into
+    /// the `x` coordinate from the even bits and the `y` coordinate from the
+    /// odd bits, returning the coordinates of the codes from `lo` in the
+    /// first four elements and from `hi` in the last four elements.
+    ///
+    /// This is the inverse of [`u8x8::morton_encode`].
+    ///
+    /// ```rust
+    /// # use eight_bytes::{u8x8, u16x4};
+    /// let lo = u16x4::from_array([0, 1, 2, 3]);
+    /// let hi = u16x4::from_array([0x5555, 0xaaaa, 0xffff, 0x8001]);
+    /// let (x, y) = u16x4::morton_decode(lo, hi);
+    /// assert_eq!(x.to_array(), [0, 1, 0, 1, 255, 0, 255, 1]);
+    /// assert_eq!(y.to_array(), [0, 0, 1, 1, 0, 255, 255, 128]);
+    /// ```
+    #[inline(always)]
+    pub const fn morton_decode(lo: Self, hi: Self) -> (u8x8, u8x8) {
+        const fn compact(v: u64) -> u64 {
+            let v = v & 0x5555555555555555;
+            let v = (v | (v >> 1)) & 0x3333333333333333;
+            let v = (v | (v >> 2)) & 0x0f0f0f0f0f0f0f0f;
+            (v | (v >> 4)) & 0x00ff00ff00ff00ff
+        }
+        (
+            Self::pack_saturating(Self::new(compact(lo.n)), Self::new(compact(hi.n))),
+            Self::pack_saturating(Self::new(compact(lo.n >> 1)), Self::new(compact(hi.n >> 1))),
+        )
+    }
+
+    /// Narrows each lane of a value from [`Self::to_le_lanes`] to a byte,
+    /// saturating at 255, and packs the bytes into the low 32 bits.
+    #[inline(always)]
+    const fn narrow_saturating_le(x: u64) -> u64 {
+        const LOW_BYTES: u64 = 0x00ff00ff00ff00ff;
+        // Adding 255 to the high byte of each lane carries into bit 8 only
+        // if the high byte was nonzero.
+        let high = ((x >> 8) & LOW_BYTES) + LOW_BYTES;
+        let over = ((high >> 8) & ALL_ONES_16) * 0xff;
+        gather_bytes(x | over)
+    }
+}
+
+impl u8x8 {
+    /// Zero-extends the elements of the vector to `u16`, returning the first
+    /// four elements in the first result and the last four in the second.
+    ///
+    /// ```rust
+    /// # use eight_bytes::{u8x8, u16x4};
+    /// let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 255]);
+    /// let (lo, hi) = v.widen();
+    /// assert_eq!(lo.to_array(), [1, 2, 3, 4]);
+    /// assert_eq!(hi.to_array(), [5, 6, 7, 255]);
+    /// ```
+    #[inline(always)]
+    pub const fn widen(self) -> (u16x4, u16x4) {
+        let le = self.n.to_le();
+        (
+            u16x4::from_le_lanes(spread_bytes(le & 0xffffffff)),
+            u16x4::from_le_lanes(spread_bytes(le >> 32)),
+        )
+    }
+
+    /// Multiplies corresponding elements of both vectors, returning the full
+    /// 16-bit products of the first four elements in the first result and
+    /// of the last four elements in the second.
+    ///
+    /// ```rust
+    /// # use eight_bytes::{u8x8, u16x4};
+    /// let a = u8x8::from_array([1, 2, 3, 4, 16, 100, 200, 255]);
+    /// let b = u8x8::from_array([5, 6, 7, 8, 16, 100, 2, 255]);
+    /// let (lo, hi) = a.widening_mul(b);
+    /// assert_eq!(lo.to_array(), [5, 12, 21, 32]);
+    /// assert_eq!(hi.to_array(), [256, 10000, 400, 65025]);
+    /// ```
+    #[inline(always)]
+    pub const fn widening_mul(self, other: Self) -> (u16x4, u16x4) {
+        let (a_lo, a_hi) = self.widen();
+        let (b_lo, b_hi) = other.widen();
+        (
+            u16x4::new(mul_lanes_16(a_lo.n, b_lo.n)),
+            u16x4::new(mul_lanes_16(a_hi.n, b_hi.n)),
+        )
+    }
+
+    /// Adds each pair of adjacent elements together, returning the four
+    /// 16-bit sums in order.
+    ///
+    /// ```rust
+    /// # use eight_bytes::{u8x8, u16x4};
+    /// let v = u8x8::from_array([1, 2, 3, 4, 100, 200, 255, 255]);
+    /// assert_eq!(v.pairwise_add().to_array(), [3, 7, 300, 510]);
+    /// ```
+    #[inline(always)]
+    pub const fn pairwise_add(self) -> u16x4 {
+        const LOW_BYTES: u64 = 0x00ff00ff00ff00ff;
+        // Both bytes of each pair fall within the same 16-bit lane on
+        // either byte order, so this doesn't need any normalization.
+        u16x4::new((self.n & LOW_BYTES) + ((self.n >> 8) & LOW_BYTES))
+    }
+
+    /// Interleaves the bits of each element of `self` with the bits of the
+    /// corresponding element of `y` to produce 16-bit Morton codes, also
+    /// known as Z-order indices, returning the codes for the first four
+    /// elements in the first result and the last four in the second.
+    ///
+    /// The bits of `self` occupy the even bit positions of each code and
+    /// the bits of `y` the odd bit positions.
+    ///
+    /// ```rust
+    /// # use eight_bytes::{u8x8, u16x4};
+    /// let x = u8x8::from_array([0, 1, 0, 1, 255, 0, 255, 1]);
+    /// let y = u8x8::from_array([0, 0, 1, 1, 0, 255, 255, 128]);
+    /// let (lo, hi) = x.morton_encode(y);
+    /// assert_eq!(lo.to_array(), [0, 1, 2, 3]);
+    /// assert_eq!(hi.to_array(), [0x5555, 0xaaaa, 0xffff, 0x8001]);
+    /// ```
+    #[inline(always)]
+    pub const fn morton_encode(self, y: Self) -> (u16x4, u16x4) {
+        const fn spread(v: u64) -> u64 {
+            let v = (v | (v << 4)) & 0x0f0f0f0f0f0f0f0f;
+            let v = (v | (v << 2)) & 0x3333333333333333;
+            (v | (v << 1)) & 0x5555555555555555
+        }
+        let (x_lo, x_hi) = self.widen();
+        let (y_lo, y_hi) = y.widen();
+        (
+            u16x4::new(spread(x_lo.n) | (spread(y_lo.n) << 1)),
+            u16x4::new(spread(x_hi.n) | (spread(y_hi.n) << 1)),
+        )
+    }
+}
+
+impl core::fmt::Debug for u16x4 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("u16x4").field(&self.to_array()).finish()
+    }
+}
+
+/// Reverses the order of the four 16-bit lanes of `n`.
+#[inline(always)]
+pub(crate) const fn reverse_lanes(n: u64) -> u64 {
+    let n = n.rotate_left(32);
+    ((n >> 16) & 0x0000ffff0000ffff) | ((n << 16) & 0xffff0000ffff0000)
+}
+
+/// Raw representation of a vector where all elements are 1.
+pub(crate) const ALL_ONES_16: u64 = 0x0001000100010001;
+
+/// Raw representation of a vector where all but the most significant bit
+/// of each element is set.
+pub(crate) const WITHOUT_HIGH_BITS_16: u64 = 0x7fff7fff7fff7fff;
+
+/// Raw representation of a vector where only the most significant bit of
+/// each element is set.
+pub(crate) const ONLY_HIGH_BITS_16: u64 = 0x8000800080008000;
@@ -0,0 +1,78 @@
+use super::*;
+
+#[test]
+pub fn from_array() {
+    let v = u16x4::from_array([1, 0x0203, 0xfffe, 0x8000]);
+    assert_eq!(v.to_array(), [1, 0x0203, 0xfffe, 0x8000]);
+    assert_eq!(u16x4::splat(0x1234).to_array(), [0x1234; 4]);
+}
+
+#[test]
+pub fn wrapping_add() {
+    let a = u16x4::from_array([1, 0x7fff, 0xffff, 0x8000]);
+    let b = u16x4::from_array([2, 1, 2, 0x8000]);
+    let got = a.wrapping_add(b);
+    let want = u16x4::from_array([3, 0x8000, 1, 0]);
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn wrapping_sub() {
+    let a = u16x4::from_array([3, 0x8000, 1, 0]);
+    let b = u16x4::from_array([2, 1, 2, 0x8000]);
+    let got = a.wrapping_sub(b);
+    let want = u16x4::from_array([1, 0x7fff, 0xffff, 0x8000]);
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn widen() {
+    let v = u8x8::from_array([0, 1, 127, 128, 200, 254, 255, 9]);
+    let (lo, hi) = v.widen();
+    assert_eq!(lo, u16x4::from_array([0, 1, 127, 128]));
+    assert_eq!(hi, u16x4::from_array([200, 254, 255, 9]));
+}
+
+#[test]
+pub fn pack_saturating() {
+    let lo = u16x4::from_array([0, 255, 256, 0x0101]);
+    let hi = u16x4::from_array([0xff00, 0xffff, 0x00fe, 0x8001]);
+    let got = u16x4::pack_saturating(lo, hi);
+    let want = u8x8::from_array([0, 255, 255, 255, 255, 255, 254, 255]);
+    assert_eq!(got, want);
+
+    let v = u8x8::from_array([0, 1, 127, 128, 200, 254, 255, 9]);
+    let (lo, hi) = v.widen();
+    assert_eq!(u16x4::pack_saturating(lo, hi), v);
+}
+
+#[test]
+pub fn widening_mul() {
+    let a = u8x8::from_array([0, 1, 2, 3, 16, 17, 128, 255]);
+    let b = u8x8::from_array([200, 200, 200, 85, 16, 15, 2, 255]);
+    let (lo, hi) = a.widening_mul(b);
+    assert_eq!(lo, u16x4::from_array([0, 200, 400, 255]));
+    assert_eq!(hi, u16x4::from_array([256, 255, 256, 65025]));
+    // Narrowing the products with saturation matches saturating_mul.
+    assert_eq!(u16x4::pack_saturating(lo, hi), a.saturating_mul(b));
+}
+
+#[test]
+pub fn pairwise_add() {
+    let v = u8x8::from_array([0, 0, 1, 255, 128, 128, 255, 255]);
+    assert_eq!(v.pairwise_add(), u16x4::from_array([0, 256, 256, 510]));
+    // Summing the pairwise sums gives the same result as reduce_sum.
+    let v = u8x8::from_array([9, 8, 7, 6, 5, 4, 3, 2]);
+    let sum: u16 = v.pairwise_add().to_array().iter().sum();
+    assert_eq!(sum as u64, v.reduce_sum());
+}
+
+#[test]
+pub fn morton() {
+    let x = u8x8::from_array([0, 1, 2, 3, 0, 7, 0x0f, 0xff]);
+    let y = u8x8::from_array([0, 0, 0, 0, 1, 7, 0xf0, 0xff]);
+    let (lo, hi) = x.morton_encode(y);
+    assert_eq!(lo, u16x4::from_array([0b0, 0b1, 0b100, 0b101]));
+    assert_eq!(hi, u16x4::from_array([0b10, 0b11_1111, 0xaa55, 0xffff]));
+    assert_eq!(u16x4::morton_decode(lo, hi), (x, y));
+}
@@ -0,0 +1,519 @@
+use crate::mask8x16;
+
+/// A vector of sixteen `u8` values, which can have SIMD-like operations
+/// applied to them without any explicit SIMD instructions.
+///
+/// This type is really just a [`u128`], but its methods interpret it as
+/// sixteen [`u8`] values where the same operation is applied to all sixteen
+/// values at once. It offers the construction, comparison, arithmetic,
+/// bitwise, byte-swapping and bit-transposing methods of [`crate::u8x8`],
+/// processing twice as many bytes per operation, which is worthwhile on
+/// targets where `u128` arithmetic is cheap.
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct u8x16 {
+    pub(crate) n: u128,
+}
+
+impl u8x16 {
+    /// A [`u8x16`] value where all sixteen elements are set to zero.
+    pub const ZEROES: Self = Self::new(0);
+
+    /// Converts an array of sixteen `u8` values into a [`u8x16`] value.
+    #[inline(always)]
+    pub const fn from_array(a: [u8; 16]) -> Self {
+        Self {
+            n: u128::from_ne_bytes(a),
+        }
+    }
+
+    /// Reinterprets the given byte slice as a slice of [`u8x16`], along with
+    /// individual leading and trailing bytes that are not aligned for
+    /// interpretation as `u128`.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x16;
+    /// let input = b"The quick brown fox jumps over the lazy dog.";
+    /// let (start, middle, end) = u8x16::from_byte_slice(input);
+    /// let space = u8x16::splat(b' ');
+    /// let mut space_count = start.iter().filter(|b| **b == b' ').count() as u32;
+    /// for v in middle {
+    ///     space_count += v.equals(space).count_true();
+    /// }
+    /// space_count += end.iter().filter(|b| **b == b' ').count() as u32;
+    /// assert_eq!(space_count, 8);
+    /// ```
+    #[inline(always)]
+    pub fn from_byte_slice(s: &[u8]) -> (&[u8], &[Self], &[u8]) {
+        const ALIGN: usize = core::mem::align_of::<u8x16>();
+        let addr = s.as_ptr().cast::<u8>() as usize;
+        let prior_count = core::cmp::min(addr.next_multiple_of(ALIGN) - addr, s.len());
+        let (prior, remain) = s.split_at(prior_count);
+        if remain.is_empty() {
+            let empty = unsafe { core::slice::from_raw_parts(core::ptr::dangling::<Self>(), 0) };
+            return (prior, empty, remain);
+        }
+        let s = remain; // now guaranteed to be correctly aligned for u128
+        let u8x16_len = s.len() / 16;
+        let use_len = u8x16_len * 16;
+        let (for_u8x16, remain) = s.split_at(use_len);
+        let ptr = for_u8x16.as_ptr().cast::<Self>();
+        let u8x16s = unsafe { core::slice::from_raw_parts(ptr, u8x16_len) };
+        (prior, u8x16s, remain)
+    }
+
+    /// Reinterprets the given byte slice as a slice of [`u8x16`], along with
+    /// individual leading and trailing bytes that are not aligned for
+    /// interpretation as `u128`.
+    ///
+    /// This is a mutable version of [`Self::from_byte_slice`].
+    #[inline(always)]
+    pub fn from_byte_slice_mut(s: &mut [u8]) -> (&mut [u8], &mut [Self], &mut [u8]) {
+        const ALIGN: usize = core::mem::align_of::<u8x16>();
+        let addr = s.as_ptr().cast::<u8>() as usize;
+        let prior_count = core::cmp::min(addr.next_multiple_of(ALIGN) - addr, s.len());
+        let (prior, remain) = s.split_at_mut(prior_count);
+        if remain.is_empty() {
+            let empty =
+                unsafe { core::slice::from_raw_parts_mut(core::ptr::dangling_mut::<Self>(), 0) };
+            return (prior, empty, remain);
+        }
+        let s = remain; // now guaranteed to be correctly aligned for u128
+        let u8x16_len = s.len() / 16;
+        let use_len = u8x16_len * 16;
+        let (for_u8x16, remain) = s.split_at_mut(use_len);
+        let ptr = for_u8x16.as_mut_ptr().cast::<Self>();
+        let u8x16s = unsafe { core::slice::from_raw_parts_mut(ptr, u8x16_len) };
+        (prior, u8x16s, remain)
+    }
+
+    #[inline(always)]
+    pub(crate) const fn new(n: u128) -> Self {
+        Self { n }
+    }
+
+    /// Returns a [`u8x16`] with `v` in all sixteen of its elements.
+    #[inline(always)]
+    pub const fn splat(v: u8) -> Self {
+        Self::new(v as u128 * ALL_ONES_128)
+    }
+
+    /// Converts the vector into an array of sixteen `u8` values.
+    #[inline(always)]
+    pub const fn to_array(self) -> [u8; 16] {
+        self.n.to_ne_bytes()
+    }
+
+    /// Computes the bitwise complement of each element in the vector.
+    #[inline(always)]
+    pub const fn complement(self) -> Self {
+        Self::new(!self.n)
+    }
+
+    /// Computes a bitwise OR result for each element across both vectors.
+    #[inline(always)]
+    pub const fn bitor(self, other: Self) -> Self {
+        Self::new(self.n | other.n)
+    }
+
+    /// Computes a bitwise AND result for each element across both vectors.
+    #[inline(always)]
+    pub const fn bitand(self, other: Self) -> Self {
+        Self::new(self.n & other.n)
+    }
+
+    /// Computes a bitwise XOR result for each element across both vectors.
+    #[inline(always)]
+    pub const fn bitxor(self, other: Self) -> Self {
+        Self::new(self.n ^ other.n)
+    }
+
+    /// Compares each element across both vectors and returns a mask value
+    /// where `true` represents equality and `false` represents inequality.
+    #[inline(always)]
+    pub const fn equals(self, other: Self) -> mask8x16 {
+        let xo = self.n ^ other.n;
+        let lo = ((xo & WITHOUT_HIGH_BITS) + WITHOUT_HIGH_BITS) | xo;
+        let hi = !lo & ONLY_HIGH_BITS;
+        mask8x16::new(hi >> 7)
+    }
+
+    /// Compares each element across both vectors and returns a mask value
+    /// with elements set to `true` where the corresponding element in `self`
+    /// is less than the corresponding element in `other`.
+    #[inline(always)]
+    pub const fn less_than(self, other: Self) -> mask8x16 {
+        let diff = (self.n | ONLY_HIGH_BITS).wrapping_sub(other.n & !ONLY_HIGH_BITS);
+        let select =
+            ((self.n & (self.n ^ other.n)) | (diff & !(self.n ^ other.n))) & ONLY_HIGH_BITS;
+        let inv = select ^ ONLY_HIGH_BITS;
+        mask8x16::new(inv >> 7)
+    }
+
+    /// Compares each element across both vectors and returns a mask value
+    /// with elements set to `true` where the corresponding element in `self`
+    /// is greater than the corresponding element in `other`.
+    #[inline(always)]
+    pub const fn greater_than(self, other: Self) -> mask8x16 {
+        other.less_than(self)
+    }
+
+    /// Implements addition across corresponding elements, modulo 256.
+    #[inline(always)]
+    pub const fn wrapping_add(self, other: Self) -> Self {
+        let low = (self.n & WITHOUT_HIGH_BITS).wrapping_add(other.n & WITHOUT_HIGH_BITS);
+        Self::new(low ^ ((self.n ^ other.n) & ONLY_HIGH_BITS))
+    }
+
+    /// Implements addition across corresponding elements, saturating at the
+    /// maximum value 255.
+    #[inline(always)]
+    pub const fn saturating_add(self, other: Self) -> Self {
+        let sum = self.wrapping_add(other).n;
+        let carry = ((self.n & other.n) | ((self.n | other.n) & !sum)) & ONLY_HIGH_BITS;
+        Self::new(sum | msb_mask(carry))
+    }
+
+    /// Returns the sum of all of the elements in the vector together.
+    ///
+    /// The maximum value of the result is 255*16=4080, but the return type
+    /// is `u64` for consistency with [`crate::u8x8::reduce_sum`].
+    #[inline(always)]
+    pub const fn reduce_sum(self) -> u64 {
+        const ALT_8S: u128 = 0x00ff00ff00ff00ff00ff00ff00ff00ff;
+        const ALT_16S: u128 = 0x0000ffff0000ffff0000ffff0000ffff;
+        const ALT_32S: u128 = 0x00000000ffffffff00000000ffffffff;
+        const ALT_64S: u128 = 0x0000000000000000ffffffffffffffff;
+
+        let mut raw = self.n;
+        raw = (raw & ALT_8S) + ((raw >> 8) & ALT_8S);
+        raw = (raw & ALT_16S) + ((raw >> 16) & ALT_16S);
+        raw = (raw & ALT_32S) + ((raw >> 32) & ALT_32S);
+        raw = (raw & ALT_64S) + ((raw >> 64) & ALT_64S);
+        raw as u64
+    }
+
+    /// Implements subtraction across corresponding elements, modulo 256.
+    #[inline(always)]
+    pub const fn wrapping_sub(self, other: Self) -> Self {
+        Self::new(
+            (self.n | ONLY_HIGH_BITS).wrapping_sub(other.n & WITHOUT_HIGH_BITS)
+                ^ ((self.n ^ !other.n) & ONLY_HIGH_BITS),
+        )
+    }
+
+    /// Implements subtraction across corresponding elements, saturating at the
+    /// minimum value 0.
+    #[inline(always)]
+    pub const fn saturating_sub(self, other: Self) -> Self {
+        let diff = self.wrapping_sub(other).n;
+        let borrow = ((!self.n & other.n) | ((!self.n | other.n) & diff)) & ONLY_HIGH_BITS;
+        Self::new(diff & !msb_mask(borrow))
+    }
+
+    /// Computes the absolute difference between corresponding elements.
+    #[inline(always)]
+    pub const fn abs_difference(self, other: Self) -> Self {
+        let msb_mask = self.borrow_mask(other);
+        let lo = (self.n & !msb_mask) | (other.n & msb_mask);
+        let hi = (self.n & msb_mask) | (other.n & !msb_mask);
+        Self::new(
+            ((lo | ONLY_HIGH_BITS) - (hi & WITHOUT_HIGH_BITS)) ^ ((lo ^ !hi) & ONLY_HIGH_BITS),
+        )
+    }
+
+    /// Finds the maximum value for each element across both vectors.
+    #[inline(always)]
+    pub const fn max(self, other: Self) -> Self {
+        let msb_mask = self.borrow_mask(other);
+        Self::new((self.n & !msb_mask) | (other.n & msb_mask))
+    }
+
+    /// Finds the minimum value for each element across both vectors.
+    #[inline(always)]
+    pub const fn min(self, other: Self) -> Self {
+        let msb_mask = self.borrow_mask(other);
+        Self::new((self.n & msb_mask) | (other.n & !msb_mask))
+    }
+
+    /// Returns a raw value with all bits set in each element where `self` is
+    /// less than `other`.
+    #[inline(always)]
+    const fn borrow_mask(self, other: Self) -> u128 {
+        let diff = self.wrapping_sub(other).n;
+        let borrow = ((!self.n & other.n) | ((!self.n | other.n) & diff)) & ONLY_HIGH_BITS;
+        msb_mask(borrow)
+    }
+
+    /// Finds the integer mean value for each element across both vectors.
+    ///
+    /// This is conceptually the same as (self + other)/2, computed without overflow.
+    #[inline(always)]
+    pub const fn mean(self, other: Self) -> Self {
+        let shared = self.n & other.n;
+        let diff = (self.n ^ other.n) & !ALL_ONES_128;
+        Self::new(shared + (diff >> 1))
+    }
+
+    /// Finds the mean value for each element across both vectors, rounding
+    /// halves upward.
+    ///
+    /// This is conceptually the same as (self + other + 1)/2, computed without
+    /// overflow.
+    #[inline(always)]
+    pub const fn rounding_mean(self, other: Self) -> Self {
+        let either = self.n | other.n;
+        let diff = (self.n ^ other.n) & !ALL_ONES_128;
+        Self::new(either - (diff >> 1))
+    }
+
+    /// Counts the number of bits set in each element.
+    #[inline(always)]
+    pub const fn popcount(self) -> Self {
+        const FIVES: u128 = ALL_ONES_128 * 0x55;
+        const THREES: u128 = ALL_ONES_128 * 0x33;
+        const LOW_NIBBLES: u128 = ALL_ONES_128 * 0x0f;
+        let a = self.n - ((self.n >> 1) & FIVES);
+        let b = (a & THREES).wrapping_add((a >> 2) & THREES);
+        Self::new((b + (b >> 4)) & LOW_NIBBLES)
+    }
+
+    /// Reverses the order of elements within each aligned pair of elements,
+    /// swapping the first element with the second, the third with the
+    /// fourth, and so on.
+    ///
+    /// When the vector holds an array of eight `u16` values, this converts
+    /// them all between big-endian and little-endian byte order.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x16;
+    /// let v = u8x16::from_array(core::array::from_fn(|i| i as u8));
+    /// assert_eq!(
+    ///     v.swap_bytes_u16().to_array(),
+    ///     [1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 15, 14],
+    /// );
+    /// ```
+    #[inline(always)]
+    #[doc(alias = "swap_pairs")]
+    pub const fn swap_bytes_u16(self) -> Self {
+        const ALT_8S: u128 = 0x00ff00ff00ff00ff00ff00ff00ff00ff;
+        Self::new(((self.n >> 8) & ALT_8S) | ((self.n << 8) & !ALT_8S))
+    }
+
+    /// Reverses the order of elements within each aligned group of four
+    /// elements.
+    ///
+    /// When the vector holds an array of four `u32` values, this converts
+    /// them all between big-endian and little-endian byte order.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x16;
+    /// let v = u8x16::from_array(core::array::from_fn(|i| i as u8));
+    /// assert_eq!(
+    ///     v.swap_bytes_u32().to_array(),
+    ///     [3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8, 15, 14, 13, 12],
+    /// );
+    /// ```
+    #[inline(always)]
+    #[doc(alias = "swap_quads")]
+    pub const fn swap_bytes_u32(self) -> Self {
+        const ALT_16S: u128 = 0x0000ffff0000ffff0000ffff0000ffff;
+        let n = self.swap_bytes_u16().n;
+        Self::new(((n >> 16) & ALT_16S) | ((n << 16) & !ALT_16S))
+    }
+
+    /// Treats each half of the vector as an 8x8 matrix of bits, as for
+    /// [`crate::u8x8::transpose_bits`], and transposes both of them.
+    ///
+    /// Bit `b` of element `i` in the result is bit `i % 8` of element
+    /// `(i / 8) * 8 + b` in `self`, with bit zero being the least significant
+    /// bit.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x16;
+    /// let mut a = [0_u8; 16];
+    /// a[0] = 0b0000_0011;
+    /// a[15] = 0b1000_0000;
+    /// let t = u8x16::from_array(a).transpose_bits();
+    /// let mut want = [0_u8; 16];
+    /// want[0] = 0b0000_0001;
+    /// want[1] = 0b0000_0001;
+    /// want[15] = 0b1000_0000;
+    /// assert_eq!(t.to_array(), want);
+    /// ```
+    #[inline(always)]
+    pub const fn transpose_bits(self) -> Self {
+        // The same delta swaps as for u8x8 never move bits between the two
+        // 64-bit halves, so repeating each mask transposes both at once.
+        let mut x = self.n.to_le();
+        let t = (x ^ (x >> 7)) & 0x00aa00aa00aa00aa00aa00aa00aa00aa;
+        x = x ^ t ^ (t << 7);
+        let t = (x ^ (x >> 14)) & 0x0000cccc0000cccc0000cccc0000cccc;
+        x = x ^ t ^ (t << 14);
+        let t = (x ^ (x >> 28)) & 0x00000000f0f0f0f000000000f0f0f0f0;
+        x = x ^ t ^ (t << 28);
+        Self::new(u128::from_le(x))
+    }
+}
+
+impl core::ops::Not for u8x16 {
+    type Output = Self;
+
+    /// Implements the unary `!` operator using [`Self::complement`].
+    fn not(self) -> Self {
+        self.complement()
+    }
+}
+
+impl core::ops::BitOr for u8x16 {
+    type Output = Self;
+
+    /// Implements the `|` operator using [`Self::bitor`].
+    fn bitor(self, rhs: Self) -> Self {
+        self.bitor(rhs)
+    }
+}
+
+impl core::ops::BitOrAssign for u8x16 {
+    /// Implements the `|=` operator using [`Self::bitor`].
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.bitor(rhs);
+    }
+}
+
+impl core::ops::BitAnd for u8x16 {
+    type Output = Self;
+
+    /// Implements the `&` operator using [`Self::bitand`].
+    fn bitand(self, rhs: Self) -> Self {
+        self.bitand(rhs)
+    }
+}
+
+impl core::ops::BitAndAssign for u8x16 {
+    /// Implements the `&=` operator using [`Self::bitand`].
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.bitand(rhs);
+    }
+}
+
+impl core::ops::BitXor for u8x16 {
+    type Output = Self;
+
+    /// Implements the `^` operator using [`Self::bitxor`].
+    fn bitxor(self, rhs: Self) -> Self {
+        self.bitxor(rhs)
+    }
+}
+
+impl core::ops::BitXorAssign for u8x16 {
+    /// Implements the `^=` operator using [`Self::bitxor`].
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.bitxor(rhs);
+    }
+}
+
+impl core::ops::Add for u8x16 {
+    type Output = Self;
+
+    /// Implements the `+` operator using [`Self::wrapping_add`].
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        self.wrapping_add(rhs)
+    }
+}
+
+impl core::ops::Add<u8> for u8x16 {
+    type Output = Self;
+
+    /// Implements the `+` operator using [`Self::wrapping_add`].
+    #[inline(always)]
+    fn add(self, rhs: u8) -> Self {
+        self.wrapping_add(Self::splat(rhs))
+    }
+}
+
+impl core::ops::AddAssign for u8x16 {
+    /// Implements the `+=` operator using [`Self::wrapping_add`].
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.wrapping_add(rhs);
+    }
+}
+
+impl core::ops::AddAssign<u8> for u8x16 {
+    /// Implements the `+=` operator using [`Self::wrapping_add`].
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: u8) {
+        *self = self.wrapping_add(Self::splat(rhs));
+    }
+}
+
+impl core::ops::Sub for u8x16 {
+    type Output = Self;
+
+    /// Implements the `-` operator using [`Self::wrapping_sub`].
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.wrapping_sub(rhs)
+    }
+}
+
+impl core::ops::Sub<u8> for u8x16 {
+    type Output = Self;
+
+    /// Implements the `-` operator using [`Self::wrapping_sub`].
+    #[inline(always)]
+    fn sub(self, rhs: u8) -> Self {
+        self.wrapping_sub(Self::splat(rhs))
+    }
+}
+
+impl core::ops::SubAssign for u8x16 {
+    /// Implements the `-=` operator using [`Self::wrapping_sub`].
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.wrapping_sub(rhs);
+    }
+}
+
+impl core::ops::SubAssign<u8> for u8x16 {
+    /// Implements the `-=` operator using [`Self::wrapping_sub`].
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: u8) {
+        *self = self.wrapping_sub(Self::splat(rhs));
+    }
+}
+
+impl IntoIterator for u8x16 {
+    type Item = u8;
+    type IntoIter = core::array::IntoIter<u8, 16>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.to_array().into_iter()
+    }
+}
+
+impl core::fmt::Debug for u8x16 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("u8x16").field(&self.to_array()).finish()
+    }
+}
+
+/// Raw representation of a vector where all bytes are 1.
+pub(crate) const ALL_ONES_128: u128 = 0x01010101010101010101010101010101;
+
+/// Raw representation of a vector where all bytes are 0x7f. See
+/// the equivalent constant for [`crate::u8x8`] for more information.
+const WITHOUT_HIGH_BITS: u128 = ALL_ONES_128 * 0x7f;
+
+/// Raw representation of a vector where all bytes are 0x80. See
+/// the equivalent constant for [`crate::u8x8`] for more information.
+const ONLY_HIGH_BITS: u128 = ALL_ONES_128 * 0x80;
+
+#[inline(always)]
+const fn msb_mask(n: u128) -> u128 {
+    (n >> 7) * 255
+}
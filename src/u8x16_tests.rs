@@ -0,0 +1,96 @@
+use super::*;
+
+#[test]
+pub fn from_array() {
+    let a: [u8; 16] = core::array::from_fn(|i| i as u8 * 17);
+    assert_eq!(u8x16::from_array(a).to_array(), a);
+    assert_eq!(u8x16::splat(0xa5).to_array(), [0xa5; 16]);
+}
+
+#[test]
+pub fn from_byte_slice() {
+    let input: [u8; 100] = core::array::from_fn(|i| i as u8);
+    for start in 0..20 {
+        let s = &input[start..];
+        let (prior, middle, rest) = u8x16::from_byte_slice(s);
+        let mut got = Vec::new();
+        got.extend_from_slice(prior);
+        for v in middle {
+            got.extend_from_slice(&v.to_array());
+        }
+        got.extend_from_slice(rest);
+        assert_eq!(got, s);
+        assert!(prior.len() < 16);
+        assert!(rest.len() < 16);
+    }
+}
+
+#[test]
+pub fn from_byte_slice_mut() {
+    let mut input: [u8; 100] = core::array::from_fn(|i| i as u8);
+    let (prior, middle, rest) = u8x16::from_byte_slice_mut(&mut input[3..]);
+    for b in prior.iter_mut().chain(rest.iter_mut()) {
+        *b = b.wrapping_add(1);
+    }
+    for v in middle {
+        *v += 1;
+    }
+    let want: [u8; 100] = core::array::from_fn(|i| if i < 3 { i as u8 } else { i as u8 + 1 });
+    assert_eq!(input, want);
+}
+
+#[test]
+pub fn comparisons() {
+    let a = u8x16::from_array([1, 2, 5, 7, 9, 9, 255, 255, 0, 0, 128, 127, 3, 4, 5, 6]);
+    let b = u8x16::from_array([1, 3, 5, 6, 9, 10, 255, 127, 0, 1, 127, 128, 3, 3, 6, 6]);
+    assert_eq!(a.equals(b).to_bitmask_le(), 0b1001_0001_0101_0101);
+    assert_eq!(a.less_than(b).to_bitmask_le(), 0b0100_1010_0010_0010);
+    assert_eq!(a.greater_than(b).to_bitmask_le(), 0b0010_0100_1000_1000);
+}
+
+#[test]
+pub fn arithmetic() {
+    let a = u8x16::from_array([1, 2, 3, 4, 255, 254, 0, 0, 10, 20, 30, 40, 200, 100, 50, 25]);
+    let b = u8x16::from_array([5, 6, 7, 8, 2, 2, 5, 2, 10, 30, 20, 40, 100, 200, 0, 26]);
+    assert_eq!(
+        a.wrapping_add(b).to_array(),
+        [6, 8, 10, 12, 1, 0, 5, 2, 20, 50, 50, 80, 44, 44, 50, 51]
+    );
+    assert_eq!(
+        a.saturating_add(b).to_array(),
+        [
+            6, 8, 10, 12, 255, 255, 5, 2, 20, 50, 50, 80, 255, 255, 50, 51
+        ]
+    );
+    assert_eq!(
+        a.saturating_sub(b).to_array(),
+        [0, 0, 0, 0, 253, 252, 0, 0, 0, 0, 10, 0, 100, 0, 50, 0]
+    );
+    assert_eq!(
+        a.abs_difference(b).to_array(),
+        [4, 4, 4, 4, 253, 252, 5, 2, 0, 10, 10, 0, 100, 100, 50, 1]
+    );
+    assert_eq!(u8x16::splat(255).reduce_sum(), 4080);
+}
+
+#[test]
+pub fn swap_bytes() {
+    let a: [u8; 16] = core::array::from_fn(|i| i as u8 * 3 + 1);
+    let v = u8x16::from_array(a);
+    let want16: [u8; 16] = core::array::from_fn(|i| a[i ^ 1]);
+    let want32: [u8; 16] = core::array::from_fn(|i| a[i ^ 3]);
+    assert_eq!(v.swap_bytes_u16().to_array(), want16);
+    assert_eq!(v.swap_bytes_u32().to_array(), want32);
+}
+
+#[test]
+pub fn transpose_bits() {
+    let a: [u8; 16] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x9d) ^ 0x5a);
+    let t = u8x16::from_array(a).transpose_bits();
+    let (lo, hi) = a.split_at(8);
+    let lo = crate::u8x8::from_array(lo.try_into().unwrap()).transpose_bits();
+    let hi = crate::u8x8::from_array(hi.try_into().unwrap()).transpose_bits();
+    assert_eq!(&t.to_array()[..8], lo.as_array());
+    assert_eq!(&t.to_array()[8..], hi.as_array());
+    assert_eq!(t.transpose_bits().to_array(), a);
+}
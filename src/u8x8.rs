@@ -8,7 +8,16 @@ use crate::mask8x8;
 /// once.
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
 pub struct u8x8 {
     pub(crate) n: u64,
 }
@@ -63,7 +72,7 @@ impl u8x8 {
     /// assert_eq!(non_space_count, 36);
     /// ```
     #[inline(always)]
-    pub fn from_byte_slice<'a>(s: &'a [u8]) -> (&'a [u8], &'a [Self], &'a [u8]) {
+    pub fn from_byte_slice(s: &[u8]) -> (&[u8], &[Self], &[u8]) {
         const ALIGN: usize = core::mem::align_of::<u8x8>();
         let addr = s.as_ptr().cast::<u8>() as usize;
         let prior_count = core::cmp::min(addr.next_multiple_of(ALIGN) - addr, s.len());
@@ -87,9 +96,7 @@ impl u8x8 {
     ///
     /// This is a mutable version of [`Self::from_byte_slice`].
     #[inline(always)]
-    pub fn from_byte_slice_mut<'a>(
-        s: &'a mut [u8],
-    ) -> (&'a mut [u8], &'a mut [Self], &'a mut [u8]) {
+    pub fn from_byte_slice_mut(s: &mut [u8]) -> (&mut [u8], &mut [Self], &mut [u8]) {
         const ALIGN: usize = core::mem::align_of::<u8x8>();
         let addr = s.as_ptr().cast::<u8>() as usize;
         let prior_count = core::cmp::min(addr.next_multiple_of(ALIGN) - addr, s.len());
@@ -125,6 +132,227 @@ impl u8x8 {
         self.n.to_ne_bytes()
     }
 
+    /// Returns a reference to the elements of the vector as an array,
+    /// without copying.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array(*b"abcdefgh");
+    /// assert_eq!(v.as_array(), b"abcdefgh");
+    /// ```
+    #[inline(always)]
+    pub const fn as_array(&self) -> &[u8; 8] {
+        // Safety: The elements are stored in native byte order, so the bytes
+        // of the u64 in memory are the elements in order. [u8; 8] has the
+        // same size as u64 and a weaker alignment requirement.
+        unsafe { &*(&self.n as *const u64).cast::<[u8; 8]>() }
+    }
+
+    /// Returns a mutable reference to the elements of the vector as an
+    /// array, without copying.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let mut v = u8x8::from_array(*b"abcdefgh");
+    /// v.as_array_mut()[1..3].copy_from_slice(b"XY");
+    /// assert_eq!(v.to_array(), *b"aXYdefgh");
+    /// ```
+    #[inline(always)]
+    pub const fn as_array_mut(&mut self) -> &mut [u8; 8] {
+        // Safety: As for as_array, and also all bit patterns are valid
+        // for both u64 and [u8; 8].
+        unsafe { &mut *(&mut self.n as *mut u64).cast::<[u8; 8]>() }
+    }
+
+    /// Applies `f` to each element separately, in index order.
+    ///
+    /// This is an escape hatch for operations that don't have a SWAR
+    /// implementation in this crate; it works one element at a time, so
+    /// it's slower than the other methods. To modify elements in place,
+    /// use [`Self::for_each_lane_mut`] instead.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    /// assert_eq!(v.map(|x| x.pow(2)).to_array(), [1, 4, 9, 16, 25, 36, 49, 64]);
+    /// ```
+    #[inline]
+    pub fn map(self, f: impl FnMut(u8) -> u8) -> Self {
+        Self::from_array(self.to_array().map(f))
+    }
+
+    /// Applies `f` to each pair of corresponding elements from `self` and
+    /// `other`, in index order.
+    ///
+    /// As with [`Self::map`], this works one element at a time.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let a = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    /// let b = u8x8::splat(3);
+    /// assert_eq!(a.zip_map(b, |a, b| a % b).to_array(), [1, 2, 0, 1, 2, 0, 1, 2]);
+    /// ```
+    #[inline]
+    pub fn zip_map(self, other: Self, mut f: impl FnMut(u8, u8) -> u8) -> Self {
+        let (a, b) = (self.to_array(), other.to_array());
+        Self::from_array(core::array::from_fn(|i| f(a[i], b[i])))
+    }
+
+    /// Calls `f` with the index of and a mutable reference to each element,
+    /// in index order.
+    ///
+    /// This is the in-place counterpart of [`Self::map`], and is likewise an
+    /// escape hatch for operations that don't have a SWAR implementation in
+    /// this crate.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let mut v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    /// v.for_each_lane_mut(|i, x| {
+    ///     if i % 2 == 0 {
+    ///         *x *= 10;
+    ///     }
+    /// });
+    /// assert_eq!(v.to_array(), [10, 2, 30, 4, 50, 6, 70, 8]);
+    /// ```
+    #[inline]
+    pub fn for_each_lane_mut(&mut self, mut f: impl FnMut(usize, &mut u8)) {
+        for (i, x) in self.as_array_mut().iter_mut().enumerate() {
+            f(i, x);
+        }
+    }
+
+    /// Creates a vector from its raw representation as a `u64`.
+    ///
+    /// Element `i` of the vector is byte `i` of `n` in the platform's native
+    /// byte order, so this is equivalent to
+    /// `u8x8::from_array(n.to_ne_bytes())`. This is intended for custom SWAR
+    /// techniques that operate directly on the integer representation; use
+    /// [`Self::to_bits`] to obtain that representation.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_bits(u64::from_le(0x0807060504030201));
+    /// assert_eq!(v.to_array(), [1, 2, 3, 4, 5, 6, 7, 8]);
+    /// ```
+    #[inline(always)]
+    pub const fn from_bits(n: u64) -> Self {
+        Self::new(n)
+    }
+
+    /// Returns the raw representation of the vector as a `u64`.
+    ///
+    /// This is the inverse of [`Self::from_bits`]. Use [`u64::to_le`] on the
+    /// result to ensure that element `i` occupies bits `8*i..8*i+8`
+    /// regardless of the platform's byte order.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    /// assert_eq!(v.to_bits().to_le(), 0x0807060504030201);
+    /// ```
+    #[inline(always)]
+    pub const fn to_bits(self) -> u64 {
+        self.n
+    }
+
+    /// Returns the element at index `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than 8.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    /// assert_eq!(v.get(0), 1);
+    /// assert_eq!(v.get(7), 8);
+    /// ```
+    #[inline(always)]
+    pub const fn get(self, i: usize) -> u8 {
+        assert!(i < 8, "element index out of range");
+        (self.n.to_le() >> (i * 8)) as u8
+    }
+
+    /// Returns a copy of the vector with the element at index `i` replaced
+    /// by `v`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than 8.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    /// assert_eq!(v.with_lane(2, 0xff).to_array(), [1, 2, 0xff, 4, 5, 6, 7, 8]);
+    /// ```
+    #[inline(always)]
+    pub const fn with_lane(self, i: usize, v: u8) -> Self {
+        assert!(i < 8, "element index out of range");
+        let shift = i * 8;
+        let le = self.n.to_le() & !(0xff << shift);
+        Self::new(u64::from_le(le | ((v as u64) << shift)))
+    }
+
+    /// Returns the element at index `I`.
+    ///
+    /// This is the same as [`Self::get`] except that the index is checked at
+    /// compile time, so an out-of-range index is a compile error rather than
+    /// a panic.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    /// assert_eq!(v.extract::<3>(), 4);
+    /// ```
+    #[inline(always)]
+    pub const fn extract<const I: usize>(self) -> u8 {
+        const { assert!(I < 8, "element index out of range") };
+        (self.n.to_le() >> (I * 8)) as u8
+    }
+
+    /// Returns a copy of the vector with the element at index `I` replaced
+    /// by `v`.
+    ///
+    /// This is the same as [`Self::with_lane`] except that the index is
+    /// checked at compile time, so an out-of-range index is a compile error
+    /// rather than a panic.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    /// assert_eq!(v.insert::<3>(0xff).to_array(), [1, 2, 3, 0xff, 5, 6, 7, 8]);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::ZEROES.insert::<8>(0xff);
+    /// ```
+    #[inline(always)]
+    pub const fn insert<const I: usize>(self, v: u8) -> Self {
+        const { assert!(I < 8, "element index out of range") };
+        let le = self.n.to_le() & !(0xff << (I * 8));
+        Self::new(u64::from_le(le | ((v as u64) << (I * 8))))
+    }
+
+    /// Returns a vector with the element at index `i` in all eight of its
+    /// elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than 8.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array(*b"abacabad");
+    /// let first = v.broadcast_lane(0);
+    /// assert_eq!(v.equals(first).to_bitmask_le(), 0b0101_0101);
+    /// ```
+    #[inline(always)]
+    pub const fn broadcast_lane(self, i: usize) -> Self {
+        Self::splat(self.get(i))
+    }
+
     /// Computes the bitwise complement of each element in the vector.
     #[inline(always)]
     pub const fn complement(self) -> Self {
@@ -161,6 +389,26 @@ impl u8x8 {
         mask8x8::new(hi >> 7)
     }
 
+    /// Compares each element across both vectors and returns a mask value
+    /// where `true` represents inequality and `false` represents equality.
+    ///
+    /// This is equivalent to `self.equals(other).not()`, but skips the
+    /// extra step of inverting the result.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let a = u8x8::from_array([0, 1, 2, 3, 4, 5, 6, 255]);
+    /// let b = u8x8::from_array([0, 2, 2, 4, 4, 6, 6, 127]);
+    /// assert_eq!(a.not_equals(b).to_bitmask_le(), 0b1010_1010);
+    /// ```
+    #[inline(always)]
+    pub const fn not_equals(self, other: Self) -> mask8x8 {
+        let xo = self.n ^ other.n;
+        let lo = ((xo & WITHOUT_HIGH_BITS) + WITHOUT_HIGH_BITS) | xo;
+        // lo has the msb set in each element that differs in any bit.
+        mask8x8::new((lo & ONLY_HIGH_BITS) >> 7)
+    }
+
     /// Compares each element across both vectors and returns a mask value
     /// with elements set to `true` where the corresponding element in `self`
     /// is less than the corresponding element in `other`.
@@ -189,6 +437,170 @@ impl u8x8 {
         mask8x8::new(inv >> 7)
     }
 
+    /// Compares each element across both vectors and returns a mask value
+    /// with elements set to `true` where the corresponding element in `self`
+    /// is less than or equal to the corresponding element in `other`.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let a = u8x8::from_array([1, 2, 5, 7, 9, 9, 255, 255]);
+    /// let b = u8x8::from_array([1, 3, 5, 6, 9, 10, 255, 127]);
+    /// assert_eq!(a.less_equal(b).to_bitmask_le(), 0b0111_0111);
+    /// ```
+    #[inline(always)]
+    pub const fn less_equal(self, other: Self) -> mask8x8 {
+        let diff = (other.n | ONLY_HIGH_BITS).wrapping_sub(self.n & !ONLY_HIGH_BITS);
+        // This is the same as greater_than except that we don't invert
+        // the selection at the end.
+        let select =
+            ((other.n & (other.n ^ self.n)) | (diff & !(other.n ^ self.n))) & ONLY_HIGH_BITS;
+        mask8x8::new(select >> 7)
+    }
+
+    /// Compares each element across both vectors and returns a mask value
+    /// with elements set to `true` where the corresponding element in `self`
+    /// is greater than or equal to the corresponding element in `other`.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let a = u8x8::from_array([1, 2, 5, 7, 9, 9, 255, 255]);
+    /// let b = u8x8::from_array([1, 3, 5, 6, 9, 10, 255, 127]);
+    /// assert_eq!(a.greater_equal(b).to_bitmask_le(), 0b1101_1101);
+    /// ```
+    #[inline(always)]
+    pub const fn greater_equal(self, other: Self) -> mask8x8 {
+        let diff = (self.n | ONLY_HIGH_BITS).wrapping_sub(other.n & !ONLY_HIGH_BITS);
+        // This is the same as less_than except that we don't invert
+        // the selection at the end.
+        let select =
+            ((self.n & (self.n ^ other.n)) | (diff & !(self.n ^ other.n))) & ONLY_HIGH_BITS;
+        mask8x8::new(select >> 7)
+    }
+
+    /// Returns a mask value with elements set to `true` where the
+    /// corresponding element is in the inclusive range `lo..=hi`.
+    ///
+    /// If `lo` is greater than `hi` then the range is empty and so all of
+    /// the elements of the result are `false`.
+    ///
+    /// This is useful for classifying characters in ASCII text:
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array(*b"a1b2/9:0");
+    /// let digits = v.in_range(b'0', b'9');
+    /// assert_eq!(digits.to_bitmask_le(), 0b1010_1010);
+    /// ```
+    #[inline(always)]
+    pub const fn in_range(self, lo: u8, hi: u8) -> mask8x8 {
+        if lo > hi {
+            return mask8x8::ALL_FALSE;
+        }
+        // Shifting the range down to start at zero makes any element that
+        // was below lo wrap around to a large value, so a single unsigned
+        // comparison then checks both ends of the range at once.
+        self.wrapping_sub(Self::splat(lo))
+            .less_equal(Self::splat(hi - lo))
+    }
+
+    /// Returns a mask value with elements set to `true` where the
+    /// corresponding element is equal to any of the values in `set`.
+    ///
+    /// This performs one [`Self::equals`] per member of the set, so it's
+    /// best suited to small sets such as a handful of delimiters. Use
+    /// [`Self::is_any_of_array`] when the set has a fixed size known at
+    /// compile time.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array(*b"a,b;c\nd\"");
+    /// let delims = v.is_any_of(b",;\n\"");
+    /// assert_eq!(delims.to_bitmask_le(), 0b1010_1010);
+    /// ```
+    #[inline]
+    pub const fn is_any_of(self, set: &[u8]) -> mask8x8 {
+        let mut ret = mask8x8::ALL_FALSE;
+        let mut i = 0;
+        while i < set.len() {
+            ret = ret.or(self.equals(Self::splat(set[i])));
+            i += 1;
+        }
+        ret
+    }
+
+    /// Returns a mask value with elements set to `true` where the
+    /// corresponding element is equal to any of the values in `set`.
+    ///
+    /// This is the same as [`Self::is_any_of`] except that the size of the
+    /// set is fixed at compile time, which allows the compiler to fully
+    /// unroll the comparisons.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array(*b"a b\tc\rd\n");
+    /// let space = v.is_any_of_array([b' ', b'\t', b'\r', b'\n']);
+    /// assert_eq!(space.to_bitmask_le(), 0b1010_1010);
+    /// ```
+    #[inline(always)]
+    pub const fn is_any_of_array<const N: usize>(self, set: [u8; N]) -> mask8x8 {
+        self.is_any_of(&set)
+    }
+
+    /// Returns `true` if any element is equal to `v`.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array(*b"abcdefgh");
+    /// assert!(v.contains(b'e'));
+    /// assert!(!v.contains(b'z'));
+    /// ```
+    #[inline(always)]
+    pub const fn contains(self, v: u8) -> bool {
+        let xo = self.n ^ Self::splat(v).n;
+        // This is the classic test for whether any byte is zero. Unlike
+        // Self::equals it can produce false positives in the elements above
+        // a zero element, but that doesn't matter when we only care about
+        // whether there's at least one.
+        xo.wrapping_sub(ALL_ONES) & !xo & ONLY_HIGH_BITS != 0
+    }
+
+    /// Returns the number of elements that are equal to `v`.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array(*b"banana!!");
+    /// assert_eq!(v.count_eq(b'a'), 3);
+    /// assert_eq!(v.count_eq(b'z'), 0);
+    /// ```
+    #[inline(always)]
+    pub const fn count_eq(self, v: u8) -> u32 {
+        // The mask has only one bit set per true element, so counting
+        // all of the set bits at once gives the number of matches.
+        self.equals(Self::splat(v)).n.count_ones()
+    }
+
+    /// Returns the index of the first element that is equal to `v`, or
+    /// `None` if no element is equal to `v`.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array(*b"key=val=");
+    /// assert_eq!(v.first_eq(b'='), Some(3));
+    /// assert_eq!(v.first_eq(b'k'), Some(0));
+    /// assert_eq!(v.first_eq(b'z'), None);
+    /// ```
+    #[inline(always)]
+    pub const fn first_eq(self, v: u8) -> Option<usize> {
+        // Normalizing to little-endian puts the first element in the
+        // least significant byte regardless of the platform byte order.
+        let raw = self.equals(Self::splat(v)).n.to_le();
+        if raw == 0 {
+            None
+        } else {
+            Some((raw.trailing_zeros() / 8) as usize)
+        }
+    }
+
     /// Implements addition across corresponding elements, modulo 256.
     #[inline(always)]
     pub const fn wrapping_add(self, other: Self) -> Self {
@@ -200,9 +612,86 @@ impl u8x8 {
     /// maximum value 255.
     #[inline(always)]
     pub const fn saturating_add(self, other: Self) -> Self {
-        let sum = self.wrapping_add(other).n;
-        let carry = ((self.n & other.n) | ((self.n | other.n) & !sum)) & ONLY_HIGH_BITS;
-        Self::new(sum | msb_mask(carry))
+        let (sum, carry) = self.overflowing_add(other);
+        Self::new(sum.n | (carry.n * 0xff))
+    }
+
+    /// Implements addition across corresponding elements, modulo 256, and
+    /// also returns a mask that is `true` for each element where the sum
+    /// wrapped around because it was greater than 255.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let a = u8x8::from_array([1, 2, 3, 4, 255, 254, 128, 0]);
+    /// let b = u8x8::from_array([5, 6, 7, 8, 2, 2, 128, 255]);
+    /// let (sum, carry) = a.overflowing_add(b);
+    /// assert_eq!(sum.to_array(), [6, 8, 10, 12, 1, 0, 0, 255]);
+    /// assert_eq!(carry.to_bitmask_le(), 0b0111_0000);
+    /// ```
+    #[inline(always)]
+    pub const fn overflowing_add(self, other: Self) -> (Self, mask8x8) {
+        let sum = self.wrapping_add(other);
+        let carry = ((self.n & other.n) | ((self.n | other.n) & !sum.n)) & ONLY_HIGH_BITS;
+        (sum, mask8x8::new(carry >> 7))
+    }
+
+    /// Adds corresponding elements along with an incoming carry of one for
+    /// each element where `carry_in` is `true`, modulo 256, and returns a
+    /// mask of the elements that produced an outgoing carry.
+    ///
+    /// This is the building block for adding numbers wider than one byte
+    /// a byte at a time, feeding each step's outgoing carries into the next.
+    ///
+    /// ```rust
+    /// # use eight_bytes::{u8x8, mask8x8};
+    /// let a = u8x8::from_array([255, 255, 1, 0, 128, 0, 0, 200]);
+    /// let b = u8x8::from_array([0, 1, 1, 0, 127, 0, 255, 100]);
+    /// let carry_in = mask8x8::from_bitmask_le(0b0101_0001);
+    /// let (sum, carry_out) = a.carrying_add(b, carry_in);
+    /// assert_eq!(sum.to_array(), [0, 0, 2, 0, 0, 0, 0, 44]);
+    /// assert_eq!(carry_out.to_bitmask_le(), 0b1101_0011);
+    /// ```
+    #[inline(always)]
+    pub const fn carrying_add(self, other: Self, carry_in: mask8x8) -> (Self, mask8x8) {
+        let (sum, c1) = self.overflowing_add(other);
+        let (sum, c2) = sum.overflowing_add(carry_in.to_u8x8());
+        // Adding one to a sum that already wrapped can't wrap again, so at
+        // most one of the two carries is set in each element.
+        (sum, c1.or(c2))
+    }
+
+    /// Implements addition across corresponding elements, or returns `None`
+    /// if any of the elements would overflow.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let a = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 250]);
+    /// let b = u8x8::splat(5);
+    /// assert_eq!(a.checked_add(b).unwrap().to_array(), [6, 7, 8, 9, 10, 11, 12, 255]);
+    /// assert_eq!(a.checked_add(u8x8::splat(6)), None);
+    /// ```
+    #[inline(always)]
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        let (sum, carry) = self.overflowing_add(other);
+        if carry.n != 0 { None } else { Some(sum) }
+    }
+
+    /// Implements addition across corresponding elements, modulo 256, but
+    /// only for the elements where `mask` is `true`. The other elements
+    /// are returned unchanged.
+    ///
+    /// ```rust
+    /// # use eight_bytes::{u8x8, mask8x8};
+    /// let a = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 255]);
+    /// let b = u8x8::splat(10);
+    /// let mask = mask8x8::from_bitmask_le(0b1000_0101);
+    /// assert_eq!(a.add_masked(b, mask).to_array(), [11, 2, 13, 4, 5, 6, 7, 9]);
+    /// ```
+    #[inline(always)]
+    pub const fn add_masked(self, other: Self, mask: mask8x8) -> Self {
+        // Adding zero leaves an element unchanged, so it's sufficient to
+        // zero out the elements of other that are not selected.
+        self.wrapping_add(Self::new(other.n & (mask.n * 0xff)))
     }
 
     /// Returns the sum of all of the elements in the vector togther.
@@ -224,6 +713,54 @@ impl u8x8 {
         raw
     }
 
+    /// Returns the bitwise AND of all of the elements in the vector together.
+    #[inline(always)]
+    pub const fn reduce_and(self) -> u8 {
+        let mut raw = self.n;
+        raw &= raw >> 32;
+        raw &= raw >> 16;
+        raw &= raw >> 8;
+        raw as u8
+    }
+
+    /// Returns the bitwise OR of all of the elements in the vector together.
+    ///
+    /// For example, this can test whether a chunk of text is entirely ASCII
+    /// by checking whether any element has its most significant bit set:
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let ascii = u8x8::from_array(*b"ascii!!!");
+    /// let utf8 = u8x8::from_array(*b"caf\xc3\xa9!!!");
+    /// assert_eq!(ascii.reduce_or() & 0x80, 0);
+    /// assert_ne!(utf8.reduce_or() & 0x80, 0);
+    /// ```
+    #[inline(always)]
+    pub const fn reduce_or(self) -> u8 {
+        let mut raw = self.n;
+        raw |= raw >> 32;
+        raw |= raw >> 16;
+        raw |= raw >> 8;
+        raw as u8
+    }
+
+    /// Returns the bitwise XOR of all of the elements in the vector together,
+    /// which is the longitudinal redundancy check of the eight bytes.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x81]);
+    /// assert_eq!(v.reduce_xor(), 0xfe);
+    /// ```
+    #[inline(always)]
+    pub const fn reduce_xor(self) -> u8 {
+        let mut raw = self.n;
+        raw ^= raw >> 32;
+        raw ^= raw >> 16;
+        raw ^= raw >> 8;
+        raw as u8
+    }
+
     /// Implements subtraction across corresponding elements, modulo 256.
     #[inline(always)]
     pub const fn wrapping_sub(self, other: Self) -> Self {
@@ -237,50 +774,925 @@ impl u8x8 {
     /// minimum value 0.
     #[inline(always)]
     pub const fn saturating_sub(self, other: Self) -> Self {
-        let diff = self.wrapping_sub(other).n;
+        let (diff, borrow) = self.borrowing_sub(other);
+        Self::new(diff.n & !(borrow.n * 0xff))
+    }
+
+    /// Implements subtraction across corresponding elements, modulo 256, and
+    /// also returns a mask that is `true` for each element where the
+    /// difference wrapped around because `other` was greater than `self`.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let a = u8x8::from_array([6, 8, 10, 12, 1, 0, 5, 255]);
+    /// let b = u8x8::from_array([1, 2, 3, 4, 255, 254, 5, 0]);
+    /// let (diff, borrow) = a.borrowing_sub(b);
+    /// assert_eq!(diff.to_array(), [5, 6, 7, 8, 2, 2, 0, 255]);
+    /// assert_eq!(borrow.to_bitmask_le(), 0b0011_0000);
+    /// ```
+    #[inline(always)]
+    pub const fn borrowing_sub(self, other: Self) -> (Self, mask8x8) {
+        let diff = self.wrapping_sub(other);
+        let borrow = ((!self.n & other.n) | ((!self.n | other.n) & diff.n)) & ONLY_HIGH_BITS;
+        (diff, mask8x8::new(borrow >> 7))
+    }
+
+    /// Implements subtraction across corresponding elements, or returns
+    /// `None` if any of the elements would underflow.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let a = u8x8::from_array([5, 6, 7, 8, 9, 10, 11, 255]);
+    /// let b = u8x8::splat(5);
+    /// assert_eq!(a.checked_sub(b).unwrap().to_array(), [0, 1, 2, 3, 4, 5, 6, 250]);
+    /// assert_eq!(a.checked_sub(u8x8::splat(6)), None);
+    /// ```
+    #[inline(always)]
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        let (diff, borrow) = self.borrowing_sub(other);
+        if borrow.n != 0 { None } else { Some(diff) }
+    }
+
+    /// Implements subtraction across corresponding elements, modulo 256, but
+    /// only for the elements where `mask` is `true`. The other elements
+    /// are returned unchanged.
+    ///
+    /// ```rust
+    /// # use eight_bytes::{u8x8, mask8x8};
+    /// let a = u8x8::from_array([11, 12, 13, 14, 15, 16, 17, 0]);
+    /// let b = u8x8::splat(10);
+    /// let mask = mask8x8::from_bitmask_le(0b1000_0101);
+    /// assert_eq!(a.sub_masked(b, mask).to_array(), [1, 12, 3, 14, 15, 16, 17, 246]);
+    /// ```
+    #[inline(always)]
+    pub const fn sub_masked(self, other: Self, mask: mask8x8) -> Self {
+        self.wrapping_sub(Self::new(other.n & (mask.n * 0xff)))
+    }
+
+    /// Computes the absolute difference between corresponding elements.
+    #[inline(always)]
+    pub const fn abs_difference(self, other: Self) -> Self {
+        let diff = self.n.wrapping_sub(other.n);
+        let borrow = ((!self.n & other.n) | ((!self.n | other.n) & diff)) & ONLY_HIGH_BITS;
+        let msb_mask = msb_mask(borrow);
+        let lo = (self.n & !msb_mask) | (other.n & msb_mask);
+        let hi = (self.n & msb_mask) | (other.n & !msb_mask);
+        Self::new(
+            ((lo | ONLY_HIGH_BITS) - (hi & WITHOUT_HIGH_BITS)) ^ ((lo ^ !hi) & ONLY_HIGH_BITS),
+        )
+    }
+
+    /// Finds the maximum value for each element across both vectors.
+    #[inline(always)]
+    pub const fn max(self, other: Self) -> Self {
+        let diff = self.n.wrapping_sub(other.n);
+        let borrow = ((!self.n & other.n) | ((!self.n | other.n) & diff)) & ONLY_HIGH_BITS;
+        let msb_mask = msb_mask(borrow);
+        Self::new((self.n & !msb_mask) | (other.n & msb_mask))
+    }
+
+    /// Finds the minimum value for each element across both vectors.
+    #[inline(always)]
+    pub const fn min(self, other: Self) -> Self {
+        let diff = self.n.wrapping_sub(other.n);
         let borrow = ((!self.n & other.n) | ((!self.n | other.n) & diff)) & ONLY_HIGH_BITS;
-        Self::new(diff & !msb_mask(borrow))
+        let msb_mask = msb_mask(borrow);
+        Self::new((self.n & msb_mask) | (other.n & !msb_mask))
+    }
+
+    /// Restricts each element to the range given by the corresponding
+    /// elements of `lo` and `hi`, inclusive.
+    ///
+    /// Unlike [`u8::clamp`], this does not panic if an element of `lo` is
+    /// greater than the corresponding element of `hi`; the result for that
+    /// element is then the element of `hi`.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([0, 5, 10, 15, 20, 25, 30, 255]);
+    /// let lo = u8x8::splat(10);
+    /// let hi = u8x8::from_array([20, 20, 20, 20, 20, 20, 40, 40]);
+    /// assert_eq!(v.clamp(lo, hi).to_array(), [10, 10, 10, 15, 20, 20, 30, 40]);
+    /// ```
+    #[inline(always)]
+    pub const fn clamp(self, lo: Self, hi: Self) -> Self {
+        self.max(lo).min(hi)
+    }
+
+    /// Restricts each element to the range `lo..=hi`.
+    ///
+    /// This is a convenience wrapper around [`Self::clamp`] for when the
+    /// bounds are the same for all elements.
+    #[inline(always)]
+    pub const fn clamp_scalar(self, lo: u8, hi: u8) -> Self {
+        self.clamp(Self::splat(lo), Self::splat(hi))
+    }
+
+    /// Interpolates linearly between corresponding elements of `self` and
+    /// `other`, computing `(self * (255 - t) + other * t) / 255` for each
+    /// element, rounded down, without overflow.
+    ///
+    /// A `t` of zero returns `self` and a `t` of 255 returns `other`, so
+    /// this is the usual formula for alpha blending `other` over `self`.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let a = u8x8::from_array([0, 100, 255, 0, 10, 200, 255, 128]);
+    /// let b = u8x8::from_array([255, 200, 0, 0, 20, 100, 255, 128]);
+    /// assert_eq!(a.lerp(b, 0), a);
+    /// assert_eq!(a.lerp(b, 255), b);
+    /// assert_eq!(a.lerp(b, 128).to_array(), [128, 150, 127, 0, 15, 149, 255, 128]);
+    /// ```
+    #[inline(always)]
+    pub const fn lerp(self, other: Self, t: u8) -> Self {
+        const LOW_BYTES: u64 = 0x00ff00ff00ff00ff;
+        // Multiplying 16-bit lanes holding at most 255 by a scalar of at most
+        // 255 can't overflow the lanes, so ordinary multiplication works.
+        let t = t as u64;
+        let inv = 255 - t;
+        let even = (self.n & LOW_BYTES) * inv + (other.n & LOW_BYTES) * t;
+        let odd = ((self.n >> 8) & LOW_BYTES) * inv + ((other.n >> 8) & LOW_BYTES) * t;
+        Self::new(div255_lanes_16(even) | (div255_lanes_16(odd) << 8))
+    }
+
+    /// Multiplies corresponding elements, treating the elements of `other`
+    /// as fractions from 0/255 to 255/255, and rounds to the nearest integer.
+    ///
+    /// This computes `(self * other + 127) / 255` exactly, which is the usual
+    /// way to scale a color channel by an opacity in compositing.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([0, 1, 2, 100, 128, 200, 254, 255]);
+    /// let f = u8x8::from_array([255, 128, 128, 128, 128, 51, 255, 0]);
+    /// assert_eq!(v.mul_frac(f).to_array(), [0, 1, 1, 50, 64, 40, 254, 0]);
+    /// ```
+    #[inline(always)]
+    pub const fn mul_frac(self, other: Self) -> Self {
+        const LOW_BYTES: u64 = 0x00ff00ff00ff00ff;
+        const fn div255_rounded(p: u64) -> u64 {
+            let t = p + 0x0080008000800080;
+            ((t + ((t >> 8) & LOW_BYTES)) >> 8) & LOW_BYTES
+        }
+        let even = mul_lanes_16(self.n & LOW_BYTES, other.n & LOW_BYTES);
+        let odd = mul_lanes_16((self.n >> 8) & LOW_BYTES, (other.n >> 8) & LOW_BYTES);
+        Self::new(div255_rounded(even) | (div255_rounded(odd) << 8))
+    }
+
+    /// Finds the integer mean value for each element across both vectors.
+    ///
+    /// This is conceptually the same as (self + other)/2, computed without overflow.
+    #[inline(always)]
+    pub const fn mean(self, other: Self) -> Self {
+        let shared = self.n & other.n;
+        let diff = (self.n ^ other.n) & 0xfefefefefefefefe;
+        Self::new(shared + (diff >> 1))
+    }
+
+    /// Implements multiplication across corresponding elements, modulo 256.
+    ///
+    /// There is no way to multiply all of the elements at once using a
+    /// single `u64` multiplication, so this instead accumulates `self`
+    /// shifted left by each bit position that is set in the corresponding
+    /// element of `other`, taking eight shift-and-add steps.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let a = u8x8::from_array([0, 1, 2, 3, 15, 16, 100, 255]);
+    /// let b = u8x8::from_array([9, 9, 9, 9, 17, 16, 3, 255]);
+    /// assert_eq!(a.wrapping_mul(b).to_array(), [0, 9, 18, 27, 255, 0, 44, 1]);
+    /// ```
+    #[inline(always)]
+    pub const fn wrapping_mul(self, other: Self) -> Self {
+        let mut acc = Self::ZEROES;
+        let mut bit = 0;
+        while bit < 8 {
+            let select = ((other.n >> bit) & ALL_ONES) * 0xff;
+            acc = acc.wrapping_add(Self::new(self.shl(bit).n & select));
+            bit += 1;
+        }
+        acc
+    }
+
+    /// Shifts each element left by `n` bits, discarding the bits that are
+    /// shifted out of each element rather than carrying them into the next.
+    ///
+    /// If `n` is eight or greater then all bits are shifted out and the
+    /// result is all zeroes.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([0, 1, 2, 3, 0x0f, 0x80, 0xf0, 0xff]);
+    /// assert_eq!(v.shl(3).to_array(), [0, 8, 16, 24, 0x78, 0, 0x80, 0xf8]);
+    /// ```
+    #[inline(always)]
+    pub const fn shl(self, n: u32) -> Self {
+        if n >= 8 {
+            return Self::ZEROES;
+        }
+        Self::new((self.n << n) & Self::splat(0xff << n).n)
+    }
+
+    /// Shifts each element right by `n` bits, discarding the bits that are
+    /// shifted out of each element rather than carrying them into the next.
+    ///
+    /// If `n` is eight or greater then all bits are shifted out and the
+    /// result is all zeroes.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([0, 1, 2, 3, 0x0f, 0x80, 0xf0, 0xff]);
+    /// assert_eq!(v.shr(3).to_array(), [0, 0, 0, 0, 0x01, 0x10, 0x1e, 0x1f]);
+    /// ```
+    #[inline(always)]
+    pub const fn shr(self, n: u32) -> Self {
+        if n >= 8 {
+            return Self::ZEROES;
+        }
+        Self::new((self.n >> n) & Self::splat(0xff >> n).n)
+    }
+
+    /// Multiplies each element by `k`, modulo 256.
+    ///
+    /// Because the multiplier is the same for all elements, this needs only
+    /// one shift-and-add step for each bit that is set in `k`, and so is
+    /// cheaper than [`Self::wrapping_mul`].
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([0, 1, 2, 3, 50, 51, 128, 255]);
+    /// assert_eq!(v.mul_scalar(5).to_array(), [0, 5, 10, 15, 250, 255, 128, 251]);
+    /// ```
+    #[inline(always)]
+    pub const fn mul_scalar(self, k: u8) -> Self {
+        let mut acc = Self::ZEROES;
+        let mut bits = k;
+        while bits != 0 {
+            acc = acc.wrapping_add(self.shl(bits.trailing_zeros()));
+            bits &= bits - 1;
+        }
+        acc
+    }
+
+    /// Divides each element by `d`, rounding toward zero.
+    ///
+    /// The division is performed by multiplying by a fixed-point reciprocal
+    /// of `d`, which is computed on each call. If `d` is known at compile
+    /// time, [`Self::div_const`] avoids that cost.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `d` is zero.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([0, 1, 6, 7, 100, 128, 254, 255]);
+    /// assert_eq!(v.div_scalar(7).to_array(), [0, 0, 0, 1, 14, 18, 36, 36]);
+    /// ```
+    #[inline(always)]
+    pub const fn div_scalar(self, d: u8) -> Self {
+        self.div_with(DivParams::new(d))
+    }
+
+    /// Returns the remainder of dividing each element by `d`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `d` is zero.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([0, 1, 6, 7, 100, 128, 254, 255]);
+    /// assert_eq!(v.rem_scalar(7).to_array(), [0, 1, 6, 0, 2, 2, 2, 3]);
+    /// ```
+    #[inline(always)]
+    pub const fn rem_scalar(self, d: u8) -> Self {
+        self.wrapping_sub(self.div_scalar(d).mul_scalar(d))
+    }
+
+    /// Divides each element by the constant `D`, rounding toward zero.
+    ///
+    /// This is the same as [`Self::div_scalar`] except that the reciprocal
+    /// of `D` is computed at compile time, and a zero divisor is a compile
+    /// error rather than a panic.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([0, 1, 9, 10, 100, 128, 254, 255]);
+    /// assert_eq!(v.div_const::<10>().to_array(), [0, 0, 0, 1, 10, 12, 25, 25]);
+    /// ```
+    #[inline(always)]
+    pub const fn div_const<const D: u8>(self) -> Self {
+        self.div_with(const { DivParams::new(D) })
+    }
+
+    /// Returns the remainder of dividing each element by `2^shift`.
+    ///
+    /// If `shift` is eight or greater then all elements are returned
+    /// unchanged, because they are all less than the divisor.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([0, 1, 7, 8, 9, 100, 128, 255]);
+    /// assert_eq!(v.rem_pow2(3).to_array(), [0, 1, 7, 0, 1, 4, 0, 7]);
+    /// ```
+    #[inline(always)]
+    pub const fn rem_pow2(self, shift: u32) -> Self {
+        if shift >= 8 {
+            return self;
+        }
+        self.mod_mask(((1_u16 << shift) - 1) as u8)
+    }
+
+    /// Masks each element with `mask`, which when `mask` is one less than a
+    /// power of two is the remainder of dividing by that power of two.
+    ///
+    /// This is the same as [`Self::bitand`] with a splatted `mask`, named
+    /// for use in hash-bucket style calculations.
+    #[inline(always)]
+    pub const fn mod_mask(self, mask: u8) -> Self {
+        self.bitand(Self::splat(mask))
+    }
+
+    #[inline(always)]
+    const fn div_with(self, p: DivParams) -> Self {
+        const LOW_BYTES: u64 = 0x00ff00ff00ff00ff;
+        const fn div_wide(x: u64, p: DivParams) -> u64 {
+            let t = (mul_lanes_16(x, p.m) >> 8) & LOW_BYTES;
+            // x is never less than t, so these can't borrow or carry between
+            // lanes, and the results never exceed 255.
+            ((t + ((x - t) >> p.sh1)) >> p.sh2) & LOW_BYTES
+        }
+        let even = div_wide(self.n & LOW_BYTES, p);
+        let odd = div_wide((self.n >> 8) & LOW_BYTES, p);
+        Self::new(even | (odd << 8))
+    }
+
+    /// Implements multiplication across corresponding elements, saturating at
+    /// the maximum value 255.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let a = u8x8::from_array([0, 1, 2, 3, 15, 16, 100, 255]);
+    /// let b = u8x8::from_array([9, 9, 9, 9, 17, 16, 3, 255]);
+    /// assert_eq!(a.saturating_mul(b).to_array(), [0, 9, 18, 27, 255, 255, 255, 255]);
+    /// ```
+    #[inline(always)]
+    pub const fn saturating_mul(self, other: Self) -> Self {
+        const LOW_BYTES: u64 = 0x00ff00ff00ff00ff;
+        const fn saturate(p: u64) -> u64 {
+            // After moving each high byte down, adding 255 carries into bit 8
+            // only in the lanes where the product overflowed a byte.
+            let high = ((p >> 8) & LOW_BYTES) + LOW_BYTES;
+            let over = ((high >> 8) & 0x0001000100010001) * 0xff;
+            (p & LOW_BYTES) | over
+        }
+        let even = mul_lanes_16(self.n & LOW_BYTES, other.n & LOW_BYTES);
+        let odd = mul_lanes_16((self.n >> 8) & LOW_BYTES, (other.n >> 8) & LOW_BYTES);
+        Self::new(saturate(even) | (saturate(odd) << 8))
+    }
+
+    /// Reverses the order of elements within each aligned pair of elements,
+    /// swapping the first element with the second, the third with the
+    /// fourth, and so on.
+    ///
+    /// When the vector holds an array of four `u16` values, this converts
+    /// them all between big-endian and little-endian byte order.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    /// assert_eq!(v.swap_bytes_u16().to_array(), [2, 1, 4, 3, 6, 5, 8, 7]);
+    /// ```
+    #[doc(alias = "swap_pairs")]
+    #[inline(always)]
+    pub const fn swap_bytes_u16(self) -> Self {
+        Self::new(((self.n >> 8) & 0x00ff00ff00ff00ff) | ((self.n << 8) & 0xff00ff00ff00ff00))
+    }
+
+    /// Reverses the order of elements within each aligned group of four
+    /// elements.
+    ///
+    /// When the vector holds an array of two `u32` values, this converts
+    /// them both between big-endian and little-endian byte order.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    /// assert_eq!(v.swap_bytes_u32().to_array(), [4, 3, 2, 1, 8, 7, 6, 5]);
+    /// ```
+    #[doc(alias = "swap_quads")]
+    #[inline(always)]
+    pub const fn swap_bytes_u32(self) -> Self {
+        let n = self.swap_bytes_u16().n;
+        Self::new(((n >> 16) & 0x0000ffff0000ffff) | ((n << 16) & 0xffff0000ffff0000))
+    }
+
+    /// Rearranges the elements so that element `i` of the result is element
+    /// `Ii` of `self`, where `I0` through `I7` are the const generic
+    /// parameters. The same element may be selected more than once.
+    ///
+    /// The indices are checked at compile time, so an out-of-range index is
+    /// a compile error rather than a panic, and the rearrangement compiles
+    /// to straight-line shift and mask operations.
+    ///
+    /// For example, this can swap the red and blue channels of two RGBA
+    /// pixels to produce BGRA:
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let rgba = u8x8::from_array([0x10, 0x20, 0x30, 0xff, 0x11, 0x21, 0x31, 0x80]);
+    /// let bgra = rgba.shuffle::<2, 1, 0, 3, 6, 5, 4, 7>();
+    /// assert_eq!(bgra.to_array(), [0x30, 0x20, 0x10, 0xff, 0x31, 0x21, 0x11, 0x80]);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::ZEROES.shuffle::<0, 1, 2, 3, 4, 5, 6, 8>();
+    /// ```
+    #[inline(always)]
+    pub const fn shuffle<
+        const I0: usize,
+        const I1: usize,
+        const I2: usize,
+        const I3: usize,
+        const I4: usize,
+        const I5: usize,
+        const I6: usize,
+        const I7: usize,
+    >(
+        self,
+    ) -> Self {
+        let idx = const {
+            let idx = [I0, I1, I2, I3, I4, I5, I6, I7];
+            let mut i = 0;
+            while i < 8 {
+                assert!(idx[i] < 8, "element index out of range");
+                i += 1;
+            }
+            idx
+        };
+        let le = self.n.to_le();
+        let mut ret = 0;
+        let mut i = 0;
+        while i < 8 {
+            ret |= ((le >> (idx[i] * 8)) & 0xff) << (i * 8);
+            i += 1;
+        }
+        Self::new(u64::from_le(ret))
+    }
+
+    /// Rearranges the elements so that element `i` of the result is the
+    /// element of `self` at the index given in element `i` of `indices`,
+    /// or zero if that index is not less than 8.
+    ///
+    /// This is similar to the `pshufb` instruction from x86's SSSE3, except
+    /// that any out-of-range index selects zero rather than only those with
+    /// their most significant bit set. Use [`Self::shuffle`] instead when
+    /// the indices are known at compile time.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([10, 11, 12, 13, 14, 15, 16, 17]);
+    /// let indices = u8x8::from_array([7, 6, 0, 0, 8, 255, 3, 1]);
+    /// assert_eq!(v.shuffle_dyn(indices).to_array(), [17, 16, 10, 10, 0, 0, 13, 11]);
+    /// ```
+    #[inline]
+    pub const fn shuffle_dyn(self, indices: Self) -> Self {
+        // Rather than extracting each index, we test all of the indices
+        // against each possible source element at once, so that the cost
+        // doesn't depend on the index values.
+        let mut ret = 0;
+        let mut j = 0;
+        while j < 8 {
+            let select = indices.equals(Self::splat(j as u8)).n * 0xff;
+            ret |= Self::splat(self.get(j)).n & select;
+            j += 1;
+        }
+        Self::new(ret)
+    }
+
+    /// Interleaves the elements of both vectors, returning the first four
+    /// elements of each interleaved in the first result and the last four
+    /// elements of each interleaved in the second.
+    ///
+    /// This can, for example, merge separate planes of two-channel data
+    /// into a single interleaved stream:
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let a = u8x8::from_array([0, 1, 2, 3, 4, 5, 6, 7]);
+    /// let b = u8x8::from_array([10, 11, 12, 13, 14, 15, 16, 17]);
+    /// let (lo, hi) = a.interleave(b);
+    /// assert_eq!(lo.to_array(), [0, 10, 1, 11, 2, 12, 3, 13]);
+    /// assert_eq!(hi.to_array(), [4, 14, 5, 15, 6, 16, 7, 17]);
+    /// ```
+    #[inline(always)]
+    pub const fn interleave(self, other: Self) -> (Self, Self) {
+        let (a, b) = (self.n.to_le(), other.n.to_le());
+        let lo = spread_bytes(a & 0xffffffff) | (spread_bytes(b & 0xffffffff) << 8);
+        let hi = spread_bytes(a >> 32) | (spread_bytes(b >> 32) << 8);
+        (Self::new(u64::from_le(lo)), Self::new(u64::from_le(hi)))
+    }
+
+    /// Separates the even-indexed elements from the odd-indexed elements of
+    /// both vectors, returning the even elements of `self` followed by the
+    /// even elements of `other` in the first result, and the odd elements in
+    /// the same order in the second.
+    ///
+    /// This is the inverse of [`Self::interleave`], and can, for example,
+    /// split interleaved two-channel data into separate planes:
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let a = u8x8::from_array([0, 10, 1, 11, 2, 12, 3, 13]);
+    /// let b = u8x8::from_array([4, 14, 5, 15, 6, 16, 7, 17]);
+    /// let (even, odd) = a.deinterleave(b);
+    /// assert_eq!(even.to_array(), [0, 1, 2, 3, 4, 5, 6, 7]);
+    /// assert_eq!(odd.to_array(), [10, 11, 12, 13, 14, 15, 16, 17]);
+    /// ```
+    #[inline(always)]
+    pub const fn deinterleave(self, other: Self) -> (Self, Self) {
+        let (a, b) = (self.n.to_le(), other.n.to_le());
+        let even = gather_bytes(a) | (gather_bytes(b) << 32);
+        let odd = gather_bytes(a >> 8) | (gather_bytes(b >> 8) << 32);
+        (Self::new(u64::from_le(even)), Self::new(u64::from_le(odd)))
+    }
+
+    /// Rotates the elements of the vector `n` places towards the first
+    /// element, with the elements that fall off the start reappearing at
+    /// the end, in the same manner as [`slice::rotate_left`].
+    ///
+    /// `n` is interpreted modulo 8.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    /// assert_eq!(v.rotate_lanes_left(3).to_array(), [4, 5, 6, 7, 8, 1, 2, 3]);
+    /// ```
+    #[inline(always)]
+    pub const fn rotate_lanes_left(self, n: u32) -> Self {
+        Self::new(u64::from_le(self.n.to_le().rotate_right((n % 8) * 8)))
+    }
+
+    /// Rotates the elements of the vector `n` places towards the last
+    /// element, with the elements that fall off the end reappearing at
+    /// the start, in the same manner as [`slice::rotate_right`].
+    ///
+    /// `n` is interpreted modulo 8.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    /// assert_eq!(v.rotate_lanes_right(3).to_array(), [6, 7, 8, 1, 2, 3, 4, 5]);
+    /// ```
+    #[inline(always)]
+    pub const fn rotate_lanes_right(self, n: u32) -> Self {
+        Self::new(u64::from_le(self.n.to_le().rotate_left((n % 8) * 8)))
+    }
+
+    /// Moves each element into the next-higher element index, discarding the
+    /// last element and placing `first` in the first element.
+    #[inline(always)]
+    pub(crate) const fn shift_elements_up(self, first: u8) -> Self {
+        Self::new(u64::from_le((self.n.to_le() << 8) | first as u64))
+    }
+
+    /// Moves each element into the next-lower element index, discarding the
+    /// first element and placing `last` in the last element.
+    #[inline(always)]
+    pub(crate) const fn shift_elements_down(self, last: u8) -> Self {
+        Self::new(u64::from_le((self.n.to_le() >> 8) | ((last as u64) << 56)))
+    }
+
+    /// Computes the running sum of elements from first to last, modulo 256.
+    ///
+    /// Element `i` of the result is the sum of elements `0..=i` of `self`.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 250]);
+    /// assert_eq!(v.wrapping_prefix_sum().to_array(), [1, 3, 6, 10, 15, 21, 28, 22]);
+    /// ```
+    #[doc(alias = "prefix_sum")]
+    #[inline(always)]
+    pub const fn wrapping_prefix_sum(self) -> Self {
+        let mut v = self;
+        v = v.wrapping_add(Self::new(u64::from_le(v.n.to_le() << 8)));
+        v = v.wrapping_add(Self::new(u64::from_le(v.n.to_le() << 16)));
+        v = v.wrapping_add(Self::new(u64::from_le(v.n.to_le() << 32)));
+        v
+    }
+
+    /// Computes the running sum of elements from first to last, saturating
+    /// at the maximum value 255.
+    ///
+    /// Element `i` of the result is the sum of elements `0..=i` of `self`,
+    /// or 255 if that sum is greater than 255.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 250]);
+    /// assert_eq!(v.saturating_prefix_sum().to_array(), [1, 3, 6, 10, 15, 21, 28, 255]);
+    /// ```
+    #[inline(always)]
+    pub const fn saturating_prefix_sum(self) -> Self {
+        // All of the partial sums are non-negative, so saturating them
+        // early can't change whether the final sum saturates.
+        let mut v = self;
+        v = v.saturating_add(Self::new(u64::from_le(v.n.to_le() << 8)));
+        v = v.saturating_add(Self::new(u64::from_le(v.n.to_le() << 16)));
+        v = v.saturating_add(Self::new(u64::from_le(v.n.to_le() << 32)));
+        v
+    }
+
+    /// Computes the running maximum of elements from first to last.
+    ///
+    /// Element `i` of the result is the maximum of elements `0..=i` of
+    /// `self`.
+    ///
+    /// For example, a vector is in non-decreasing order exactly when it is
+    /// equal to its own running maximum:
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([3, 1, 4, 1, 5, 9, 2, 6]);
+    /// assert_eq!(v.prefix_max().to_array(), [3, 3, 4, 4, 5, 9, 9, 9]);
+    /// assert_ne!(v.prefix_max(), v);
+    /// ```
+    #[inline(always)]
+    pub const fn prefix_max(self) -> Self {
+        // Shifting in zeroes is fine because zero never exceeds any element.
+        let mut v = self;
+        v = v.max(Self::new(u64::from_le(v.n.to_le() << 8)));
+        v = v.max(Self::new(u64::from_le(v.n.to_le() << 16)));
+        v = v.max(Self::new(u64::from_le(v.n.to_le() << 32)));
+        v
+    }
+
+    /// Replaces each element with the entry from `table` at the index given
+    /// by the low four bits of that element, ignoring the high four bits.
+    ///
+    /// The cost doesn't depend on the element values, and is lower when
+    /// `table` is a constant because the compiler can then precompute each
+    /// of the table entries splatted across a whole vector.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// const HEX: [u8; 16] = *b"0123456789ABCDEF";
+    /// let v = u8x8::from_array([0x00, 0x01, 0x0a, 0x0f, 0x10, 0x2b, 0xc3, 0xff]);
+    /// assert_eq!(&v.lookup_nibble(HEX).to_array(), b"01AF0B3F");
+    /// ```
+    #[inline(always)]
+    pub const fn lookup_nibble(self, table: [u8; 16]) -> Self {
+        // This is a tree of two-way selections, one level per index bit.
+        let mut level = [0_u64; 16];
+        let mut i = 0;
+        while i < 16 {
+            level[i] = Self::splat(table[i]).n;
+            i += 1;
+        }
+        let mut len = 16;
+        let mut bit = 0;
+        while bit < 4 {
+            let mask = ((self.n >> bit) & ALL_ONES) * 0xff;
+            let mut i = 0;
+            while i < len / 2 {
+                level[i] = (level[i * 2] & !mask) | (level[i * 2 + 1] & mask);
+                i += 1;
+            }
+            len /= 2;
+            bit += 1;
+        }
+        Self::new(level[0])
+    }
+
+    /// Replaces each element with the entry from `table` at the index given
+    /// by the high four bits of that element, ignoring the low four bits.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// const HEX: [u8; 16] = *b"0123456789ABCDEF";
+    /// let v = u8x8::from_array([0x00, 0x01, 0x0a, 0x0f, 0x10, 0x2b, 0xc3, 0xff]);
+    /// assert_eq!(&v.lookup_high_nibble(HEX).to_array(), b"000012CF");
+    /// ```
+    #[inline(always)]
+    pub const fn lookup_high_nibble(self, table: [u8; 16]) -> Self {
+        // Shifting the whole vector moves the high four bits of each element
+        // into the low four bits, and lookup_nibble ignores whatever arrives
+        // from the neighboring element.
+        Self::new(self.n >> 4).lookup_nibble(table)
+    }
+
+    /// Treats the vector as an 8x8 matrix of bits where each element is a row
+    /// and each bit position is a column, and returns its transpose.
+    ///
+    /// Bit `b` of element `i` in the result is bit `i` of element `b` in
+    /// `self`, with bit zero being the least significant bit.
+    ///
+    /// This is useful for converting between bitplane and byte-per-pixel
+    /// representations of images, and for rotating 1bpp 8x8 tiles.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([0b0000_0011, 0, 0, 0, 0, 0, 0, 0b1000_0000]);
+    /// let t = v.transpose_bits();
+    /// assert_eq!(t.to_array(), [0b0000_0001, 0b0000_0001, 0, 0, 0, 0, 0, 0b1000_0000]);
+    /// ```
+    #[inline(always)]
+    pub const fn transpose_bits(self) -> Self {
+        let mut x = self.n.to_le();
+        let t = (x ^ (x >> 7)) & 0x00aa00aa00aa00aa;
+        x = x ^ t ^ (t << 7);
+        let t = (x ^ (x >> 14)) & 0x0000cccc0000cccc;
+        x = x ^ t ^ (t << 14);
+        let t = (x ^ (x >> 28)) & 0x00000000f0f0f0f0;
+        x = x ^ t ^ (t << 28);
+        Self::new(u64::from_le(x))
+    }
+
+    /// Treats the vector as an 8x8 1bpp tile whose first element is the top
+    /// row and whose most significant bits are the leftmost column, and
+    /// mirrors it horizontally by reversing the bits of each element.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([0b1100_0000, 0b0000_0001, 0, 0, 0, 0, 0, 0b1010_0000]);
+    /// let f = v.flip_bits_horizontal();
+    /// assert_eq!(f.to_array(), [0b0000_0011, 0b1000_0000, 0, 0, 0, 0, 0, 0b0000_0101]);
+    /// ```
+    #[inline(always)]
+    pub const fn flip_bits_horizontal(self) -> Self {
+        let mut x = self.n;
+        x = ((x >> 1) & 0x5555555555555555) | ((x & 0x5555555555555555) << 1);
+        x = ((x >> 2) & 0x3333333333333333) | ((x & 0x3333333333333333) << 2);
+        x = ((x >> 4) & 0x0f0f0f0f0f0f0f0f) | ((x & 0x0f0f0f0f0f0f0f0f) << 4);
+        Self::new(x)
+    }
+
+    /// Treats the vector as an 8x8 1bpp tile whose first element is the top
+    /// row, and mirrors it vertically by reversing the order of the elements.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    /// assert_eq!(v.flip_bits_vertical().to_array(), [8, 7, 6, 5, 4, 3, 2, 1]);
+    /// ```
+    #[inline(always)]
+    pub const fn flip_bits_vertical(self) -> Self {
+        Self::new(self.n.swap_bytes())
+    }
+
+    /// Treats the vector as an 8x8 1bpp tile whose first element is the top
+    /// row and whose most significant bits are the leftmost column, and
+    /// rotates it a quarter turn clockwise.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// // An arrow pointing up becomes an arrow pointing right.
+    /// let up = u8x8::from_array([
+    ///     0b0001_1000,
+    ///     0b0011_1100,
+    ///     0b0111_1110,
+    ///     0b0001_1000,
+    ///     0b0001_1000,
+    ///     0b0001_1000,
+    ///     0b0001_1000,
+    ///     0b0000_0000,
+    /// ]);
+    /// let right = up.rotate_bits_90_cw();
+    /// assert_eq!(right.to_array(), [
+    ///     0b0000_0000,
+    ///     0b0000_0100,
+    ///     0b0000_0110,
+    ///     0b0111_1111,
+    ///     0b0111_1111,
+    ///     0b0000_0110,
+    ///     0b0000_0100,
+    ///     0b0000_0000,
+    /// ]);
+    /// ```
+    #[inline(always)]
+    pub const fn rotate_bits_90_cw(self) -> Self {
+        self.transpose_bits().flip_bits_vertical()
+    }
+
+    /// Treats the vector as an 8x8 1bpp tile whose first element is the top
+    /// row and whose most significant bits are the leftmost column, and
+    /// rotates it a quarter turn counterclockwise.
+    ///
+    /// This is the inverse of [`Self::rotate_bits_90_cw`].
+    #[inline(always)]
+    pub const fn rotate_bits_90_ccw(self) -> Self {
+        self.transpose_bits().flip_bits_horizontal()
+    }
+
+    /// Finds the integer mean value for each element across both vectors,
+    /// rounding halves upward.
+    ///
+    /// This is conceptually the same as (self + other + 1)/2, computed without
+    /// overflow.
+    #[inline(always)]
+    pub const fn rounding_mean(self, other: Self) -> Self {
+        let either = self.n | other.n;
+        let diff = (self.n ^ other.n) & 0xfefefefefefefefe;
+        Self::new(either - (diff >> 1))
+    }
+
+    /// Counts the number of leading zero bits in each element, giving 8 for
+    /// elements that are zero.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([0, 1, 2, 3, 0x10, 0x7f, 0x80, 0xff]);
+    /// assert_eq!(v.leading_zeros().to_array(), [8, 7, 6, 6, 3, 1, 0, 0]);
+    /// ```
+    #[inline(always)]
+    pub const fn leading_zeros(self) -> Self {
+        // Smearing the highest set bit of each element into all of the lower
+        // bits leaves exactly the leading zeros unset.
+        let mut x = self;
+        x = x.bitor(x.shr(1));
+        x = x.bitor(x.shr(2));
+        x = x.bitor(x.shr(4));
+        Self::splat(8).wrapping_sub(x.popcount())
     }
 
-    /// Computes the absolute difference between corresponding elements.
+    /// Counts the number of trailing zero bits in each element, giving 8 for
+    /// elements that are zero.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([0, 1, 2, 3, 0x10, 0x7f, 0x80, 0xff]);
+    /// assert_eq!(v.trailing_zeros().to_array(), [8, 0, 1, 0, 4, 0, 7, 0]);
+    /// ```
     #[inline(always)]
-    pub const fn abs_difference(self, other: Self) -> Self {
-        let diff = self.n.wrapping_sub(other.n);
-        let borrow = ((!self.n & other.n) | ((!self.n | other.n) & diff)) & ONLY_HIGH_BITS;
-        let msb_mask = msb_mask(borrow);
-        let lo = (self.n & !msb_mask) | (other.n & msb_mask);
-        let hi = (self.n & msb_mask) | (other.n & !msb_mask);
-        Self::new(
-            ((lo | ONLY_HIGH_BITS) - (hi & WITHOUT_HIGH_BITS)) ^ ((lo ^ !hi) & ONLY_HIGH_BITS),
-        )
+    pub const fn trailing_zeros(self) -> Self {
+        // Isolating the lowest set bit of each element and then subtracting
+        // one sets exactly the trailing zero bits. Zero elements become 0xff.
+        let lowest = self.bitand(Self::ZEROES.wrapping_sub(self));
+        lowest.wrapping_sub(Self::splat(1)).popcount()
     }
 
-    /// Finds the maximum value for each element across both vectors.
+    /// Computes the base-2 logarithm of each element, rounded down.
+    ///
+    /// The logarithm of zero is undefined, so zero elements produce 255,
+    /// which can't be the logarithm of any `u8` value. Use
+    /// [`Self::checked_ilog2`] to detect zero elements instead.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([0, 1, 2, 3, 4, 0x7f, 0x80, 0xff]);
+    /// assert_eq!(v.ilog2().to_array(), [255, 0, 1, 1, 2, 6, 7, 7]);
+    /// ```
     #[inline(always)]
-    pub const fn max(self, other: Self) -> Self {
-        let diff = self.n.wrapping_sub(other.n);
-        let borrow = ((!self.n & other.n) | ((!self.n | other.n) & diff)) & ONLY_HIGH_BITS;
-        let msb_mask = msb_mask(borrow);
-        Self::new((self.n & !msb_mask) | (other.n & msb_mask))
+    pub const fn ilog2(self) -> Self {
+        Self::splat(7).wrapping_sub(self.leading_zeros())
     }
 
-    /// Finds the minimum value for each element across both vectors.
+    /// Computes the base-2 logarithm of each element, rounded down, or
+    /// returns `None` if any element is zero.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([1, 2, 3, 4, 5, 0x7f, 0x80, 0xff]);
+    /// assert_eq!(v.checked_ilog2().unwrap().to_array(), [0, 1, 1, 2, 2, 6, 7, 7]);
+    /// assert_eq!(u8x8::ZEROES.checked_ilog2(), None);
+    /// ```
     #[inline(always)]
-    pub const fn min(self, other: Self) -> Self {
-        let diff = self.n.wrapping_sub(other.n);
-        let borrow = ((!self.n & other.n) | ((!self.n | other.n) & diff)) & ONLY_HIGH_BITS;
-        let msb_mask = msb_mask(borrow);
-        Self::new((self.n & msb_mask) | (other.n & !msb_mask))
+    pub const fn checked_ilog2(self) -> Option<Self> {
+        let log = self.ilog2();
+        // Only the zero elements produce a result with the high bit set.
+        if log.n & ONLY_HIGH_BITS != 0 {
+            None
+        } else {
+            Some(log)
+        }
     }
 
-    /// Finds the integer mean value for each element across both vectors.
+    /// Returns a mask that is `true` for each element that is a power of two.
     ///
-    /// This is conceptually the same as (self + other)/2, computed without overflow.
+    /// Zero is not a power of two. Use [`Self::is_zero_or_power_of_two`]
+    /// to also accept zero elements.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([0, 1, 2, 3, 4, 0x40, 0x80, 0xff]);
+    /// let want = [false, true, true, false, true, true, true, false];
+    /// assert_eq!(v.is_power_of_two().to_array(), want);
+    /// ```
     #[inline(always)]
-    pub const fn mean(self, other: Self) -> Self {
-        let shared = self.n & other.n;
-        let diff = (self.n ^ other.n) & 0xfefefefefefefefe;
-        Self::new(shared + (diff >> 1))
+    pub const fn is_power_of_two(self) -> mask8x8 {
+        self.is_zero_or_power_of_two()
+            .and(self.equals(Self::ZEROES).not())
+    }
+
+    /// Returns a mask that is `true` for each element that is either zero or
+    /// a power of two, which is to say that it has at most one bit set.
+    #[inline(always)]
+    pub const fn is_zero_or_power_of_two(self) -> mask8x8 {
+        self.bitand(self.wrapping_sub(Self::splat(1)))
+            .equals(Self::ZEROES)
     }
 
     /// Counts the number of bits set in each element.
@@ -290,6 +1702,83 @@ impl u8x8 {
         let b = (a & 0x3333333333333333).wrapping_add((a >> 2) & 0x3333333333333333);
         Self::new((b + (b >> 4)) & 0x0f0f0f0f0f0f0f0f)
     }
+
+    /// For each element, takes the bits of `self` at the positions where
+    /// the corresponding element of `mask` has bits set and packs them
+    /// together into the least significant bits of the result, like the
+    /// `pext` instruction from x86's BMI2.
+    ///
+    /// For example, this can extract a three-bit field from the middle of
+    /// each byte of a protocol header:
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let v = u8x8::from_array([0b1010_1100, 0b0001_1100, 0b1110_0011, 0, 0xff, 1, 2, 3]);
+    /// let mask = u8x8::splat(0b0011_1000);
+    /// let fields = v.extract_bits(mask);
+    /// assert_eq!(fields.to_array(), [0b101, 0b011, 0b100, 0, 0b111, 0, 0, 0]);
+    /// ```
+    #[inline]
+    pub const fn extract_bits(self, mask: Self) -> Self {
+        // dest has one bit set in each element, marking where the next
+        // selected bit of that element belongs in the result.
+        let mut dest = ALL_ONES;
+        let mut ret = 0;
+        let mut bit = 0;
+        while bit < 8 {
+            let select = ((mask.n >> bit) & ALL_ONES) * 0xff;
+            let value = ((self.n >> bit) & ALL_ONES) * 0xff;
+            ret |= dest & value & select;
+            dest = (dest & !select) | ((dest << 1) & 0xfefefefefefefefe & select);
+            bit += 1;
+        }
+        Self::new(ret)
+    }
+
+    /// For each element, takes the least significant bits of `self` and
+    /// places them at the positions where the corresponding element of
+    /// `mask` has bits set, in order, leaving the other bits of the result
+    /// unset, like the `pdep` instruction from x86's BMI2.
+    ///
+    /// This is the inverse of [`Self::extract_bits`] for the bits selected
+    /// by `mask`.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let fields = u8x8::from_array([0b101, 0b011, 0b100, 0, 0b111, 0, 0, 0]);
+    /// let mask = u8x8::splat(0b0011_1000);
+    /// let v = fields.deposit_bits(mask);
+    /// assert_eq!(v.to_array(), [0b0010_1000, 0b0001_1000, 0b0010_0000, 0, 0b0011_1000, 0, 0, 0]);
+    /// ```
+    #[inline]
+    pub const fn deposit_bits(self, mask: Self) -> Self {
+        // src holds the bits of each element that have not been deposited
+        // yet, shifted down so that the next one is the least significant.
+        let mut src = self.n;
+        let mut ret = 0;
+        let mut bit = 0;
+        while bit < 8 {
+            let select = ((mask.n >> bit) & ALL_ONES) * 0xff;
+            ret |= (src & ALL_ONES & select) << bit;
+            src = (src & !select) | ((src >> 1) & WITHOUT_HIGH_BITS & select);
+            bit += 1;
+        }
+        Self::new(ret)
+    }
+
+    /// Returns the total number of bits that differ between the two vectors,
+    /// treating each as a 64-bit string.
+    ///
+    /// ```rust
+    /// # use eight_bytes::u8x8;
+    /// let a = u8x8::from_array([0x00, 0xff, 0x0f, 0x01, 0, 0, 0, 0]);
+    /// let b = u8x8::from_array([0x00, 0x00, 0xf0, 0x03, 0, 0, 0, 0x80]);
+    /// assert_eq!(a.hamming_distance(b), 18);
+    /// ```
+    #[inline(always)]
+    pub const fn hamming_distance(self, other: Self) -> u32 {
+        (self.n ^ other.n).count_ones()
+    }
 }
 
 impl core::ops::Not for u8x8 {
@@ -421,6 +1910,288 @@ impl core::ops::SubAssign<u8> for u8x8 {
     }
 }
 
+impl core::ops::Mul for u8x8 {
+    type Output = Self;
+
+    /// Implements the `*` operator using [`Self::wrapping_mul`].
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self {
+        self.wrapping_mul(rhs)
+    }
+}
+
+impl core::ops::Mul<u8> for u8x8 {
+    type Output = Self;
+
+    /// Implements the `*` operator using [`Self::mul_scalar`].
+    #[inline(always)]
+    fn mul(self, rhs: u8) -> Self {
+        self.mul_scalar(rhs)
+    }
+}
+
+impl core::ops::MulAssign for u8x8 {
+    /// Implements the `*=` operator using [`Self::wrapping_mul`].
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.wrapping_mul(rhs);
+    }
+}
+
+impl core::ops::MulAssign<u8> for u8x8 {
+    /// Implements the `*=` operator using [`Self::mul_scalar`].
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: u8) {
+        *self = self.mul_scalar(rhs);
+    }
+}
+
+impl core::ops::Shl<u32> for u8x8 {
+    type Output = Self;
+
+    /// Implements the `<<` operator using [`Self::shl`].
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self {
+        self.shl(rhs)
+    }
+}
+
+impl core::ops::ShlAssign<u32> for u8x8 {
+    /// Implements the `<<=` operator using [`Self::shl`].
+    #[inline(always)]
+    fn shl_assign(&mut self, rhs: u32) {
+        *self = self.shl(rhs);
+    }
+}
+
+impl core::ops::Shr<u32> for u8x8 {
+    type Output = Self;
+
+    /// Implements the `>>` operator using [`Self::shr`].
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self {
+        self.shr(rhs)
+    }
+}
+
+impl core::ops::ShrAssign<u32> for u8x8 {
+    /// Implements the `>>=` operator using [`Self::shr`].
+    #[inline(always)]
+    fn shr_assign(&mut self, rhs: u32) {
+        *self = self.shr(rhs);
+    }
+}
+
+impl PartialOrd for u8x8 {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for u8x8 {
+    /// Compares vectors lexicographically from the first element to the last,
+    /// in the same way as the equivalent `[u8; 8]` arrays.
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        // Reading the bytes as big-endian makes the first element the most
+        // significant, regardless of the platform's byte order.
+        u64::from_be(self.n).cmp(&u64::from_be(other.n))
+    }
+}
+
+impl core::ops::Index<usize> for u8x8 {
+    type Output = u8;
+
+    /// Implements `v[i]` using [`Self::as_array`].
+    #[inline(always)]
+    fn index(&self, i: usize) -> &u8 {
+        &self.as_array()[i]
+    }
+}
+
+impl core::ops::IndexMut<usize> for u8x8 {
+    /// Implements mutable `v[i]` using [`Self::as_array_mut`].
+    #[inline(always)]
+    fn index_mut(&mut self, i: usize) -> &mut u8 {
+        &mut self.as_array_mut()[i]
+    }
+}
+
+impl core::iter::Sum for u8x8 {
+    /// Sums the vectors element-wise using [`Self::wrapping_add`].
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZEROES, Self::wrapping_add)
+    }
+}
+
+impl<'a> core::iter::Sum<&'a u8x8> for u8x8 {
+    /// Sums the vectors element-wise using [`Self::wrapping_add`].
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl core::iter::Product for u8x8 {
+    /// Multiplies the vectors element-wise using [`Self::wrapping_mul`].
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::splat(1), Self::wrapping_mul)
+    }
+}
+
+impl<'a> core::iter::Product<&'a u8x8> for u8x8 {
+    /// Multiplies the vectors element-wise using [`Self::wrapping_mul`].
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl From<[u8; 8]> for u8x8 {
+    /// Converts an array using [`Self::from_array`].
+    #[inline(always)]
+    fn from(a: [u8; 8]) -> Self {
+        Self::from_array(a)
+    }
+}
+
+impl From<u8x8> for [u8; 8] {
+    /// Converts a vector using [`u8x8::to_array`].
+    #[inline(always)]
+    fn from(v: u8x8) -> Self {
+        v.to_array()
+    }
+}
+
+impl From<u64> for u8x8 {
+    /// Converts a raw representation using [`Self::from_bits`].
+    #[inline(always)]
+    fn from(n: u64) -> Self {
+        Self::from_bits(n)
+    }
+}
+
+impl From<u8x8> for u64 {
+    /// Converts a vector to its raw representation using [`u8x8::to_bits`].
+    #[inline(always)]
+    fn from(v: u8x8) -> Self {
+        v.to_bits()
+    }
+}
+
+impl TryFrom<&[u8]> for u8x8 {
+    type Error = core::array::TryFromSliceError;
+
+    /// Converts a slice of exactly eight bytes into a vector, or returns an
+    /// error if the slice has any other length.
+    #[inline(always)]
+    fn try_from(s: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::from_array(s.try_into()?))
+    }
+}
+
+/// Parses a vector from one of two textual forms:
+///
+/// - Eight hexadecimal elements separated by colons, like
+///   `"01:02:ff:00:00:00:00:00"`.
+/// - Eight decimal elements in square brackets, separated either by commas
+///   or by whitespace, like `"[1, 2, 255, 0, 0, 0, 0, 0]"`. The brackets may
+///   be preceded by `u8x8`, so the output of the [`Display`](core::fmt::Display)
+///   implementation can be parsed back again.
+///
+/// Leading and trailing whitespace is ignored in both forms.
+///
+/// ```rust
+/// # use eight_bytes::u8x8;
+/// let want = u8x8::from_array([1, 2, 255, 0, 0, 0, 0, 0]);
+/// assert_eq!("01:02:ff:00:00:00:00:00".parse(), Ok(want));
+/// assert_eq!("[1, 2, 255, 0, 0, 0, 0, 0]".parse(), Ok(want));
+/// assert_eq!(want.to_string().parse(), Ok(want));
+/// ```
+impl core::str::FromStr for u8x8 {
+    type Err = ParseU8x8Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let bracketed = s
+            .strip_prefix("u8x8")
+            .unwrap_or(s)
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'));
+        match bracketed {
+            Some(inner) if inner.contains(',') => {
+                parse_elements(inner.split(',').map(str::trim), 10)
+            }
+            Some(inner) => parse_elements(inner.split_whitespace(), 10),
+            None => parse_elements(s.split(':'), 16),
+        }
+    }
+}
+
+fn parse_elements<'a>(
+    elems: impl Iterator<Item = &'a str>,
+    radix: u32,
+) -> Result<u8x8, ParseU8x8Error> {
+    let mut ret = [0_u8; 8];
+    let mut len = 0;
+    for elem in elems {
+        if len == ret.len() {
+            return Err(ParseU8x8Error::WrongLength);
+        }
+        // from_str_radix would also accept a leading sign, which we don't
+        // want to allow here.
+        if elem.is_empty() || !elem.chars().all(|c| c.is_digit(radix)) {
+            return Err(ParseU8x8Error::InvalidElement);
+        }
+        ret[len] = u8::from_str_radix(elem, radix).map_err(|_| ParseU8x8Error::InvalidElement)?;
+        len += 1;
+    }
+    if len != ret.len() {
+        return Err(ParseU8x8Error::WrongLength);
+    }
+    Ok(u8x8::from_array(ret))
+}
+
+/// Error returned when parsing a [`u8x8`] from a string fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseU8x8Error {
+    /// An element is not a valid number in the expected base, or is out of
+    /// range for `u8`.
+    InvalidElement,
+    /// The string does not contain exactly eight elements.
+    WrongLength,
+}
+
+impl core::fmt::Display for ParseU8x8Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseU8x8Error::InvalidElement => f.write_str("invalid vector element"),
+            ParseU8x8Error::WrongLength => f.write_str("vector must have exactly eight elements"),
+        }
+    }
+}
+
+impl core::error::Error for ParseU8x8Error {}
+
+/// Collects up to eight elements from an iterator, setting any elements the
+/// iterator doesn't provide to zero.
+///
+/// Any items after the first eight are ignored.
+///
+/// ```rust
+/// # use eight_bytes::u8x8;
+/// let v: u8x8 = b"abc".iter().copied().collect();
+/// assert_eq!(v.to_array(), [b'a', b'b', b'c', 0, 0, 0, 0, 0]);
+/// ```
+impl FromIterator<u8> for u8x8 {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        let mut ret = [0_u8; 8];
+        for (dst, v) in ret.iter_mut().zip(iter) {
+            *dst = v;
+        }
+        Self::from_array(ret)
+    }
+}
+
 impl IntoIterator for u8x8 {
     type Item = u8;
     type IntoIter = core::array::IntoIter<u8, 8>;
@@ -431,12 +2202,151 @@ impl IntoIterator for u8x8 {
     }
 }
 
+impl IntoIterator for &u8x8 {
+    type Item = u8;
+    type IntoIter = core::array::IntoIter<u8, 8>;
+
+    /// Iterates over the elements by value, just as for an owned vector.
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.to_array().into_iter()
+    }
+}
+
+// Safety: u8x8 is a transparent wrapper around u64, which is Pod, and
+// every bit pattern is a valid vector.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for u8x8 {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for u8x8 {}
+
+/// Serializes as an array of eight `u8` values in index order, so the
+/// serialized form doesn't depend on the platform's byte order.
+#[cfg(feature = "serde")]
+impl serde::Serialize for u8x8 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_array().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for u8x8 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <[u8; 8]>::deserialize(deserializer).map(Self::from_array)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for u8x8 {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "u8x8({=[u8; 8]})", self.to_array())
+    }
+}
+
+/// Generates vectors whose elements are each uniformly distributed over all
+/// `u8` values.
+///
+/// Element `i` is byte `i` of a little-endian `u64` from the generator, so a
+/// seeded generator produces the same vectors on all platforms.
+#[cfg(feature = "rand")]
+impl rand::distr::Distribution<u8x8> for rand::distr::StandardUniform {
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> u8x8 {
+        u8x8::from_array(rng.next_u64().to_le_bytes())
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::Fill for u8x8 {
+    /// Fills each vector with random bytes, in the same order as they would
+    /// be generated for a byte slice.
+    fn fill_slice<R: rand::Rng + ?Sized>(this: &mut [Self], rng: &mut R) {
+        for v in this {
+            rng.fill_bytes(v.as_array_mut());
+        }
+    }
+}
+
 impl core::fmt::Debug for u8x8 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("u8x8").field(&self.to_array()).finish()
     }
 }
 
+/// Formats the elements in index order as decimal numbers, like
+/// `u8x8[1 255 0 0 0 0 0 0]`.
+///
+/// Any width, fill or alignment options apply to each element separately.
+impl core::fmt::Display for u8x8 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_lanes(f, |v, f| core::fmt::Display::fmt(&v, f))
+    }
+}
+
+/// Formats the elements in index order as two-digit lowercase hexadecimal
+/// numbers, like `u8x8[01 ff 00 00 00 00 00 00]`.
+///
+/// The alternate flag adds a `0x` prefix to each element. Any width, fill or
+/// alignment options apply to each element separately, replacing the
+/// default zero-padding to two digits.
+impl core::fmt::LowerHex for u8x8 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_lanes(f, |v, f| match (f.width(), f.alternate()) {
+            (None, false) => write!(f, "{v:02x}"),
+            (None, true) => write!(f, "{v:#04x}"),
+            _ => core::fmt::LowerHex::fmt(&v, f),
+        })
+    }
+}
+
+/// Formats the elements in index order as two-digit uppercase hexadecimal
+/// numbers, like `u8x8[01 FF 00 00 00 00 00 00]`.
+///
+/// Formatter options are handled as for the [`LowerHex`](core::fmt::LowerHex)
+/// implementation.
+impl core::fmt::UpperHex for u8x8 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_lanes(f, |v, f| match (f.width(), f.alternate()) {
+            (None, false) => write!(f, "{v:02X}"),
+            (None, true) => write!(f, "{v:#04X}"),
+            _ => core::fmt::UpperHex::fmt(&v, f),
+        })
+    }
+}
+
+/// Formats the elements in index order as eight-digit binary numbers, like
+/// `u8x8[00000001 11111111 00000000 …]`.
+///
+/// The alternate flag adds a `0b` prefix to each element. Any width, fill or
+/// alignment options apply to each element separately, replacing the
+/// default zero-padding to eight digits.
+impl core::fmt::Binary for u8x8 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_lanes(f, |v, f| match (f.width(), f.alternate()) {
+            (None, false) => write!(f, "{v:08b}"),
+            (None, true) => write!(f, "{v:#010b}"),
+            _ => core::fmt::Binary::fmt(&v, f),
+        })
+    }
+}
+
+impl u8x8 {
+    fn fmt_lanes(
+        self,
+        f: &mut core::fmt::Formatter<'_>,
+        lane: impl Fn(u8, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        f.write_str("u8x8[")?;
+        for (i, v) in self.to_array().into_iter().enumerate() {
+            if i != 0 {
+                f.write_str(" ")?;
+            }
+            lane(v, f)?;
+        }
+        f.write_str("]")
+    }
+}
+
 /// Raw representation of a vector where all bytes are 1.
 pub(crate) const ALL_ONES: u64 = 0x0101010101010101;
 
@@ -452,9 +2362,76 @@ const WITHOUT_HIGH_BITS: u64 = 0x7f7f7f7f7f7f7f7f;
 ///
 /// This is the complement of [`WITHOUT_HIGH_BITS`], used to deal with the
 /// masked-out remnant of a wrapping operation.
-const ONLY_HIGH_BITS: u64 = 0x8080808080808080;
+pub(crate) const ONLY_HIGH_BITS: u64 = 0x8080808080808080;
 
 #[inline(always)]
-const fn msb_mask(n: u64) -> u64 {
+pub(crate) const fn msb_mask(n: u64) -> u64 {
     (n >> 7) * 255
 }
+
+/// Moves each of the four bytes in the low 32 bits of `x` into the low byte
+/// of the corresponding 16-bit lane, leaving the high bytes zero.
+#[inline(always)]
+pub(crate) const fn spread_bytes(x: u64) -> u64 {
+    let x = (x | (x << 16)) & 0x0000ffff0000ffff;
+    (x | (x << 8)) & 0x00ff00ff00ff00ff
+}
+
+/// Moves the low byte of each 16-bit lane of `x` into the corresponding
+/// byte of the low 32 bits, ignoring the high bytes. This is the inverse of
+/// [`spread_bytes`].
+#[inline(always)]
+pub(crate) const fn gather_bytes(x: u64) -> u64 {
+    let x = x & 0x00ff00ff00ff00ff;
+    let x = (x | (x >> 8)) & 0x0000ffff0000ffff;
+    (x | (x >> 16)) & 0x00000000ffffffff
+}
+
+/// Multiplies corresponding 16-bit lanes of `a` and `b`, each of which must
+/// be no greater than 255 so that the products cannot overflow their lanes.
+#[inline(always)]
+pub(crate) const fn mul_lanes_16(a: u64, b: u64) -> u64 {
+    // Each partial sum is no greater than the final product, so plain
+    // addition can't carry between lanes.
+    let mut acc = 0;
+    let mut bit = 0;
+    while bit < 8 {
+        let select = ((b >> bit) & 0x0001000100010001) * 0xffff;
+        acc += (a << bit) & select;
+        bit += 1;
+    }
+    acc
+}
+
+/// Parameters for dividing 8-bit values by a particular divisor using the
+/// method from Granlund and Montgomery's "Division by Invariant Integers
+/// using Multiplication".
+#[derive(Clone, Copy)]
+struct DivParams {
+    /// The low eight bits of the nine-bit reciprocal, splatted across four
+    /// 16-bit lanes.
+    m: u64,
+    sh1: u32,
+    sh2: u32,
+}
+
+impl DivParams {
+    const fn new(d: u8) -> Self {
+        assert!(d != 0, "attempt to divide by zero");
+        let l = (d as u32).next_power_of_two().trailing_zeros();
+        let m = ((256 * ((1_u32 << l) - d as u32)) / d as u32) + 1;
+        Self {
+            m: m as u64 * 0x0001000100010001,
+            sh1: if l < 1 { l } else { 1 },
+            sh2: l.saturating_sub(1),
+        }
+    }
+}
+
+/// Divides each 16-bit lane by 255, rounding down, for lane values up to
+/// `255 * 255`, returning results in the low byte of each lane.
+#[inline(always)]
+pub(crate) const fn div255_lanes_16(x: u64) -> u64 {
+    const LOW_BYTES: u64 = 0x00ff00ff00ff00ff;
+    ((x + ((x >> 8) & LOW_BYTES) + 0x0001000100010001) >> 8) & LOW_BYTES
+}
@@ -9,6 +9,16 @@ pub fn equals() {
     assert_eq!(got, want);
 }
 
+#[test]
+pub fn not_equals() {
+    let a = u8x8::from_array([1, 2, 5, 6, 9, 10, 255, 255]);
+    let b = u8x8::from_array([1, 3, 5, 7, 9, 10, 255, 127]);
+    let got = a.not_equals(b);
+    let want = mask8x8::from_array([false, true, false, true, false, false, false, true]);
+    assert_eq!(got, want);
+    assert_eq!(got, a.equals(b).not());
+}
+
 #[test]
 pub fn less_than() {
     let a = u8x8::from_array([1, 2, 5, 7, 9, 9, 255, 255]);
@@ -27,6 +37,24 @@ pub fn greater_than() {
     assert_eq!(got, want);
 }
 
+#[test]
+pub fn less_equal() {
+    let a = u8x8::from_array([1, 2, 5, 7, 9, 9, 255, 255]);
+    let b = u8x8::from_array([1, 3, 5, 6, 9, 10, 255, 127]);
+    let got = a.less_equal(b);
+    let want = mask8x8::from_array([true, true, true, false, true, true, true, false]);
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn greater_equal() {
+    let a = u8x8::from_array([1, 2, 5, 7, 9, 9, 255, 255]);
+    let b = u8x8::from_array([1, 3, 5, 6, 9, 10, 255, 127]);
+    let got = a.greater_equal(b);
+    let want = mask8x8::from_array([true, false, true, true, true, false, true, true]);
+    assert_eq!(got, want);
+}
+
 #[test]
 pub fn wrapping_add() {
     let a = u8x8::from_array([1, 2, 3, 4, 255, 254, 0, 0]);
@@ -51,6 +79,17 @@ pub fn collect_sum() {
     assert_eq!(values.reduce_sum(), 402);
 }
 
+#[test]
+pub fn reduce_bitwise() {
+    let values = u8x8::from_array([0xf1, 0xf2, 0xf4, 0xf8, 0xf0, 0xf0, 0xf0, 0xff]);
+    assert_eq!(values.reduce_and(), 0xf0);
+    assert_eq!(values.reduce_or(), 0xff);
+    assert_eq!(values.reduce_xor(), 0x00);
+    assert_eq!(u8x8::ZEROES.reduce_and(), 0);
+    assert_eq!(u8x8::splat(0xa5).reduce_and(), 0xa5);
+    assert_eq!(u8x8::splat(0xa5).reduce_xor(), 0);
+}
+
 #[test]
 pub fn wrapping_sub() {
     let a = u8x8::from_array([6, 8, 10, 12, 1, 0, 5, 2]);
@@ -105,6 +144,15 @@ pub fn mean() {
     assert_eq!(got, want);
 }
 
+#[test]
+pub fn rounding_mean() {
+    let a = u8x8::from_array([0, 1, 2, 3, 127, 128, 254, 255]);
+    let b = u8x8::from_array([255, 255, 254, 3, 255, 0, 64, 0]);
+    let got = a.rounding_mean(b);
+    let want = u8x8::from_array([128, 128, 128, 3, 191, 64, 159, 128]);
+    assert_eq!(got, want);
+}
+
 #[test]
 pub fn popcount() {
     let a = u8x8::from_array([0x00, 0x01, 0x10, 0x03, 0x0f, 0xf0, 0xff, 0xfe]);
@@ -112,3 +160,829 @@ pub fn popcount() {
     let want = u8x8::from_array([0, 1, 1, 2, 4, 4, 8, 7]);
     assert_eq!(got, want);
 }
+
+#[test]
+pub fn hamming_distance() {
+    let a = u8x8::from_array([0x00, 0x01, 0x10, 0x03, 0x0f, 0xf0, 0xff, 0xfe]);
+    assert_eq!(a.hamming_distance(a), 0);
+    assert_eq!(a.hamming_distance(u8x8::ZEROES), 27);
+    assert_eq!(a.hamming_distance(a.complement()), 64);
+    assert_eq!(
+        a.hamming_distance(u8x8::ZEROES) as u64,
+        a.popcount().reduce_sum()
+    );
+}
+
+#[test]
+pub fn transpose_bits() {
+    let a = u8x8::from_array([0xff, 0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40]);
+    let got = a.transpose_bits();
+    let want = u8x8::from_array([0x03, 0x05, 0x09, 0x11, 0x21, 0x41, 0x81, 0x01]);
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn bit_tile_transforms() {
+    // Only the top-left pixel is set.
+    let v = u8x8::from_array([0x80, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(
+        v.flip_bits_horizontal().to_array(),
+        [0x01, 0, 0, 0, 0, 0, 0, 0],
+    );
+    assert_eq!(
+        v.flip_bits_vertical().to_array(),
+        [0, 0, 0, 0, 0, 0, 0, 0x80]
+    );
+    assert_eq!(
+        v.rotate_bits_90_cw().to_array(),
+        [0x01, 0, 0, 0, 0, 0, 0, 0]
+    );
+    assert_eq!(
+        v.rotate_bits_90_ccw().to_array(),
+        [0, 0, 0, 0, 0, 0, 0, 0x80]
+    );
+
+    let v = u8x8::from_array([0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0]);
+    assert_eq!(v.rotate_bits_90_cw().rotate_bits_90_ccw(), v);
+    assert_eq!(
+        v.rotate_bits_90_cw().rotate_bits_90_cw(),
+        v.flip_bits_horizontal().flip_bits_vertical(),
+    );
+    assert_eq!(v.flip_bits_horizontal().flip_bits_horizontal(), v);
+}
+
+#[test]
+pub fn swap_bytes_u16() {
+    let a = u8x8::from_array([0, 1, 2, 3, 4, 5, 6, 7]);
+    let got = a.swap_bytes_u16();
+    let want = u8x8::from_array([1, 0, 3, 2, 5, 4, 7, 6]);
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn swap_bytes_u32() {
+    let a = u8x8::from_array([0, 1, 2, 3, 4, 5, 6, 7]);
+    let got = a.swap_bytes_u32();
+    let want = u8x8::from_array([3, 2, 1, 0, 7, 6, 5, 4]);
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn wrapping_mul() {
+    let a = u8x8::from_array([0, 1, 2, 3, 16, 17, 128, 255]);
+    let b = u8x8::from_array([200, 200, 200, 85, 16, 15, 2, 255]);
+    let got = a.wrapping_mul(b);
+    let want = u8x8::from_array([0, 200, 144, 255, 0, 255, 0, 1]);
+    assert_eq!(got, want);
+    assert_eq!(a * b, want);
+}
+
+#[test]
+pub fn saturating_mul() {
+    let a = u8x8::from_array([0, 1, 2, 3, 16, 17, 128, 255]);
+    let b = u8x8::from_array([200, 200, 200, 85, 15, 15, 2, 1]);
+    let got = a.saturating_mul(b);
+    let want = u8x8::from_array([0, 200, 255, 255, 240, 255, 255, 255]);
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn mul_scalar() {
+    let v = u8x8::from_array([0, 1, 2, 3, 16, 17, 128, 255]);
+    assert_eq!(v.mul_scalar(0), u8x8::ZEROES);
+    assert_eq!(v.mul_scalar(1), v);
+    let want = u8x8::from_array([0, 15, 30, 45, 240, 255, 128, 241]);
+    assert_eq!(v.mul_scalar(15), want);
+    assert_eq!(v * 15, want);
+}
+
+#[test]
+pub fn div_scalar() {
+    let v = u8x8::from_array([0, 1, 2, 3, 99, 100, 128, 255]);
+    assert_eq!(v.div_scalar(1), v);
+    let want = u8x8::from_array([0, 0, 0, 1, 33, 33, 42, 85]);
+    assert_eq!(v.div_scalar(3), want);
+    assert_eq!(v.div_const::<3>(), want);
+    let want = u8x8::from_array([0, 0, 0, 0, 0, 0, 0, 1]);
+    assert_eq!(v.div_const::<255>(), want);
+}
+
+#[test]
+pub fn rem_scalar() {
+    let v = u8x8::from_array([0, 1, 2, 3, 99, 100, 128, 255]);
+    assert_eq!(v.rem_scalar(1), u8x8::ZEROES);
+    let want = u8x8::from_array([0, 1, 2, 0, 0, 1, 2, 0]);
+    assert_eq!(v.rem_scalar(3), want);
+}
+
+#[test]
+#[should_panic]
+pub fn div_scalar_zero() {
+    u8x8::splat(1).div_scalar(0);
+}
+
+#[test]
+pub fn rem_pow2() {
+    let v = u8x8::from_array([0, 1, 2, 3, 15, 16, 128, 255]);
+    assert_eq!(v.rem_pow2(0), u8x8::ZEROES);
+    assert_eq!(v.rem_pow2(1), u8x8::from_array([0, 1, 0, 1, 1, 0, 0, 1]));
+    assert_eq!(v.rem_pow2(4), u8x8::from_array([0, 1, 2, 3, 15, 0, 0, 15]));
+    assert_eq!(v.rem_pow2(8), v);
+    assert_eq!(v.rem_pow2(40), v);
+    assert_eq!(v.mod_mask(0x0f), v.rem_pow2(4));
+}
+
+#[test]
+pub fn shl() {
+    let v = u8x8::from_array([0, 1, 2, 3, 0x0f, 0x80, 0xf0, 0xff]);
+    assert_eq!(v.shl(0), v);
+    let want = u8x8::from_array([0, 2, 4, 6, 0x1e, 0, 0xe0, 0xfe]);
+    assert_eq!(v.shl(1), want);
+    let want = u8x8::from_array([0, 0x80, 0, 0x80, 0x80, 0, 0, 0x80]);
+    assert_eq!(v.shl(7), want);
+    assert_eq!(v.shl(8), u8x8::ZEROES);
+}
+
+#[test]
+pub fn shr() {
+    let v = u8x8::from_array([0, 1, 2, 3, 0x0f, 0x80, 0xf0, 0xff]);
+    assert_eq!(v.shr(0), v);
+    let want = u8x8::from_array([0, 0, 1, 1, 0x07, 0x40, 0x78, 0x7f]);
+    assert_eq!(v.shr(1), want);
+    let want = u8x8::from_array([0, 0, 0, 0, 0, 1, 1, 1]);
+    assert_eq!(v.shr(7), want);
+    assert_eq!(v.shr(100), u8x8::ZEROES);
+}
+
+#[test]
+pub fn shift_operators() {
+    let v = u8x8::from_array([0, 1, 2, 3, 0x0f, 0x80, 0xf0, 0xff]);
+    assert_eq!(v << 2, v.shl(2));
+    assert_eq!(v >> 2, v.shr(2));
+    let mut w = v;
+    w <<= 4;
+    w >>= 4;
+    assert_eq!(w, v.mod_mask(0x0f));
+}
+
+#[test]
+pub fn leading_zeros() {
+    let v = u8x8::from_array([0, 1, 0x0f, 0x10, 0x3f, 0x40, 0x81, 0xff]);
+    let want = u8x8::from_array([8, 7, 4, 3, 2, 1, 0, 0]);
+    assert_eq!(v.leading_zeros(), want);
+}
+
+#[test]
+pub fn trailing_zeros() {
+    let v = u8x8::from_array([0, 1, 0x0c, 0x10, 0x3f, 0x40, 0x80, 0xfe]);
+    let want = u8x8::from_array([8, 0, 2, 4, 0, 6, 7, 1]);
+    assert_eq!(v.trailing_zeros(), want);
+}
+
+#[test]
+pub fn ilog2() {
+    let v = u8x8::from_array([1, 2, 7, 8, 0x3f, 0x40, 0x80, 0xff]);
+    let want = u8x8::from_array([0, 1, 2, 3, 5, 6, 7, 7]);
+    assert_eq!(v.ilog2(), want);
+    assert_eq!(v.checked_ilog2(), Some(want));
+
+    let v = u8x8::from_array([1, 2, 7, 8, 0, 0x40, 0x80, 0xff]);
+    assert_eq!(v.ilog2().to_array()[4], 255);
+    assert_eq!(v.checked_ilog2(), None);
+}
+
+#[test]
+pub fn is_power_of_two() {
+    let v = u8x8::from_array([0, 1, 2, 6, 8, 0x41, 0x80, 0xff]);
+    let got = v.is_power_of_two();
+    let want = mask8x8::from_array([false, true, true, false, true, false, true, false]);
+    assert_eq!(got, want);
+    let got = v.is_zero_or_power_of_two();
+    let want = mask8x8::from_array([true, true, true, false, true, false, true, false]);
+    assert_eq!(got, want);
+}
+
+#[test]
+pub fn clamp() {
+    let v = u8x8::from_array([0, 9, 10, 11, 99, 100, 101, 255]);
+    let want = u8x8::from_array([10, 10, 10, 11, 99, 100, 100, 100]);
+    assert_eq!(v.clamp_scalar(10, 100), want);
+    assert_eq!(v.clamp(u8x8::splat(10), u8x8::splat(100)), want);
+    // Inverted bounds produce the upper bound.
+    assert_eq!(v.clamp_scalar(100, 10), u8x8::splat(10));
+}
+
+#[test]
+pub fn lerp() {
+    let a = u8x8::from_array([0, 0, 255, 255, 10, 20, 100, 200]);
+    let b = u8x8::from_array([255, 0, 0, 255, 20, 10, 200, 100]);
+    assert_eq!(a.lerp(b, 0), a);
+    assert_eq!(a.lerp(b, 255), b);
+    let want = u8x8::from_array([51, 0, 204, 255, 12, 18, 120, 180]);
+    assert_eq!(a.lerp(b, 51), want);
+}
+
+#[test]
+pub fn mul_frac() {
+    let a = u8x8::from_array([0, 255, 255, 255, 100, 100, 3, 200]);
+    let b = u8x8::from_array([0, 0, 255, 1, 255, 127, 85, 191]);
+    let want = u8x8::from_array([0, 0, 255, 1, 100, 50, 1, 150]);
+    assert_eq!(a.mul_frac(b), want);
+}
+
+#[test]
+pub fn overflowing_add() {
+    let a = u8x8::from_array([1, 2, 3, 4, 255, 254, 0, 128]);
+    let b = u8x8::from_array([5, 6, 7, 8, 2, 2, 5, 127]);
+    let (sum, carry) = a.overflowing_add(b);
+    assert_eq!(sum, u8x8::from_array([6, 8, 10, 12, 1, 0, 5, 255]));
+    let want = mask8x8::from_array([false, false, false, false, true, true, false, false]);
+    assert_eq!(carry, want);
+}
+
+#[test]
+pub fn borrowing_sub() {
+    let a = u8x8::from_array([6, 8, 10, 12, 1, 0, 5, 2]);
+    let b = u8x8::from_array([1, 2, 3, 4, 255, 254, 5, 3]);
+    let (diff, borrow) = a.borrowing_sub(b);
+    assert_eq!(diff, u8x8::from_array([5, 6, 7, 8, 2, 2, 0, 255]));
+    let want = mask8x8::from_array([false, false, false, false, true, true, false, true]);
+    assert_eq!(borrow, want);
+}
+
+#[test]
+pub fn carrying_add() {
+    // Adds two 64-bit little-endian numbers one byte at a time, by
+    // propagating carries from each element to the next.
+    let x: u64 = 0x01ff_ffff_00ff_8001;
+    let y: u64 = 0x0000_0001_0001_80ff;
+    let a = u8x8::from_array(x.to_le_bytes());
+    let b = u8x8::from_array(y.to_le_bytes());
+    let (mut sum, mut carry) = a.carrying_add(b, mask8x8::ALL_FALSE);
+    while carry.to_bitmask_le() & 0x7f != 0 {
+        let shifted = mask8x8::from_bitmask_le(carry.to_bitmask_le() << 1);
+        (sum, carry) = sum.carrying_add(u8x8::ZEROES, shifted);
+    }
+    assert_eq!(u64::from_le_bytes(sum.to_array()), x.wrapping_add(y));
+}
+
+#[test]
+pub fn checked_add_sub() {
+    let a = u8x8::from_array([0, 1, 2, 3, 252, 253, 254, 255]);
+    let b = u8x8::from_array([255, 254, 253, 252, 3, 2, 1, 0]);
+    assert_eq!(a.checked_add(b), Some(u8x8::splat(255)));
+    assert_eq!(a.checked_add(u8x8::splat(1)), None);
+    assert_eq!(a.checked_sub(a), Some(u8x8::ZEROES));
+    assert_eq!(a.checked_sub(b), None);
+    assert_eq!(u8x8::splat(255).checked_sub(b), Some(a));
+}
+
+#[test]
+pub fn add_sub_masked() {
+    let a = u8x8::from_array([0, 1, 2, 3, 252, 253, 254, 255]);
+    let b = u8x8::splat(2);
+    assert_eq!(a.add_masked(b, mask8x8::ALL_FALSE), a);
+    assert_eq!(a.add_masked(b, mask8x8::ALL_TRUE), a.wrapping_add(b));
+    assert_eq!(a.sub_masked(b, mask8x8::ALL_FALSE), a);
+    assert_eq!(a.sub_masked(b, mask8x8::ALL_TRUE), a.wrapping_sub(b));
+
+    // Conditionally decrementing only the non-zero elements is a common
+    // use: the mask prevents the zero elements from wrapping around.
+    let nonzero = a.equals(u8x8::ZEROES).not();
+    assert_eq!(
+        a.sub_masked(u8x8::splat(1), nonzero).to_array(),
+        [0, 0, 1, 2, 251, 252, 253, 254],
+    );
+}
+
+#[test]
+pub fn in_range() {
+    let v = u8x8::from_array([0, 9, 10, 11, 19, 20, 21, 255]);
+    assert_eq!(
+        v.in_range(10, 20).to_array(),
+        [false, false, true, true, true, true, false, false],
+    );
+    assert_eq!(v.in_range(0, 255), mask8x8::ALL_TRUE);
+    assert_eq!(
+        v.in_range(255, 255).to_array(),
+        [false, false, false, false, false, false, false, true],
+    );
+    assert_eq!(v.in_range(20, 10), mask8x8::ALL_FALSE);
+}
+
+#[test]
+pub fn is_any_of() {
+    let v = u8x8::from_array(*b"x,y;z\n\"w");
+    assert_eq!(v.is_any_of(&[]), mask8x8::ALL_FALSE);
+    assert_eq!(
+        v.is_any_of(b",").to_array(),
+        [false, true, false, false, false, false, false, false],
+    );
+    assert_eq!(
+        v.is_any_of(b",;\n\"").to_array(),
+        [false, true, false, true, false, true, true, false],
+    );
+    assert_eq!(v.is_any_of_array(*b",;\n\""), v.is_any_of(b",;\n\""));
+    assert_eq!(v.is_any_of_array(*b"xyzw,;\n\""), mask8x8::ALL_TRUE);
+}
+
+#[test]
+pub fn contains() {
+    let v = u8x8::from_array([0, 1, 2, 3, 0x80, 0x81, 0xfe, 0xff]);
+    for x in v.to_array() {
+        assert!(v.contains(x), "{x}");
+    }
+    for x in [4, 0x7f, 0x82, 0xfd] {
+        assert!(!v.contains(x), "{x}");
+    }
+    assert!(u8x8::ZEROES.contains(0));
+    assert!(!u8x8::ZEROES.contains(1));
+}
+
+#[test]
+pub fn count_eq() {
+    let v = u8x8::from_array([0, 7, 0, 7, 7, 0xff, 0x80, 7]);
+    assert_eq!(v.count_eq(7), 4);
+    assert_eq!(v.count_eq(0), 2);
+    assert_eq!(v.count_eq(0xff), 1);
+    assert_eq!(v.count_eq(0x7f), 0);
+    assert_eq!(u8x8::splat(9).count_eq(9), 8);
+}
+
+#[test]
+pub fn first_eq() {
+    let v = u8x8::from_array([9, 1, 2, 1, 4, 5, 6, 0]);
+    assert_eq!(v.first_eq(9), Some(0));
+    assert_eq!(v.first_eq(1), Some(1));
+    assert_eq!(v.first_eq(0), Some(7));
+    assert_eq!(v.first_eq(3), None);
+    assert_eq!(u8x8::ZEROES.first_eq(0), Some(0));
+}
+
+#[test]
+pub fn get_with_lane() {
+    let a = [0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88];
+    let v = u8x8::from_array(a);
+    for (i, want) in a.into_iter().enumerate() {
+        assert_eq!(v.get(i), want);
+    }
+    let mut w = u8x8::ZEROES;
+    for (i, x) in a.into_iter().enumerate() {
+        w = w.with_lane(i, x);
+    }
+    assert_eq!(w, v);
+    assert_eq!(
+        v.with_lane(7, 0).to_array(),
+        [0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0]
+    );
+}
+
+#[test]
+#[should_panic]
+pub fn get_out_of_range() {
+    u8x8::ZEROES.get(8);
+}
+
+#[test]
+pub fn extract_insert() {
+    let v = u8x8::from_array([0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+    assert_eq!(v.extract::<0>(), 0x11);
+    assert_eq!(v.extract::<5>(), 0x66);
+    assert_eq!(v.extract::<7>(), 0x88);
+    assert_eq!(v.insert::<0>(0), v.with_lane(0, 0));
+    assert_eq!(v.insert::<4>(0xab), v.with_lane(4, 0xab));
+    assert_eq!(v.insert::<7>(0xff).extract::<7>(), 0xff);
+}
+
+#[test]
+pub fn shuffle() {
+    let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(v.shuffle::<0, 1, 2, 3, 4, 5, 6, 7>(), v);
+    assert_eq!(v.shuffle::<1, 0, 3, 2, 5, 4, 7, 6>(), v.swap_bytes_u16());
+    assert_eq!(v.shuffle::<3, 2, 1, 0, 7, 6, 5, 4>(), v.swap_bytes_u32());
+    assert_eq!(
+        v.shuffle::<7, 7, 0, 0, 4, 4, 2, 1>().to_array(),
+        [8, 8, 1, 1, 5, 5, 3, 2],
+    );
+}
+
+#[test]
+pub fn rotate_lanes() {
+    let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(v.rotate_lanes_left(0), v);
+    assert_eq!(v.rotate_lanes_left(8), v);
+    assert_eq!(v.rotate_lanes_left(1).to_array(), [2, 3, 4, 5, 6, 7, 8, 1],);
+    assert_eq!(v.rotate_lanes_right(1).to_array(), [8, 1, 2, 3, 4, 5, 6, 7],);
+    assert_eq!(v.rotate_lanes_left(5).rotate_lanes_right(5), v);
+    assert_eq!(v.rotate_lanes_left(10), v.rotate_lanes_left(2));
+}
+
+#[test]
+pub fn broadcast_lane() {
+    let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    for i in 0..8 {
+        assert_eq!(v.broadcast_lane(i), u8x8::splat(i as u8 + 1));
+    }
+}
+
+#[test]
+pub fn interleave() {
+    let a = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    let (lo, hi) = a.interleave(u8x8::ZEROES);
+    assert_eq!(lo.to_array(), [1, 0, 2, 0, 3, 0, 4, 0]);
+    assert_eq!(hi.to_array(), [5, 0, 6, 0, 7, 0, 8, 0]);
+    let (lo, hi) = u8x8::ZEROES.interleave(a);
+    assert_eq!(lo.to_array(), [0, 1, 0, 2, 0, 3, 0, 4]);
+    assert_eq!(hi.to_array(), [0, 5, 0, 6, 0, 7, 0, 8]);
+}
+
+#[test]
+pub fn deinterleave() {
+    let a = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    let b = u8x8::from_array([9, 10, 11, 12, 13, 14, 15, 16]);
+    let (even, odd) = a.deinterleave(b);
+    assert_eq!(even.to_array(), [1, 3, 5, 7, 9, 11, 13, 15]);
+    assert_eq!(odd.to_array(), [2, 4, 6, 8, 10, 12, 14, 16]);
+
+    let (lo, hi) = a.interleave(b);
+    assert_eq!(lo.deinterleave(hi), (a, b));
+}
+
+#[test]
+pub fn shuffle_dyn() {
+    let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    let identity = u8x8::from_array([0, 1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(v.shuffle_dyn(identity), v);
+    assert_eq!(
+        v.shuffle_dyn(u8x8::from_array([3, 2, 1, 0, 7, 6, 5, 4])),
+        v.swap_bytes_u32(),
+    );
+    assert_eq!(v.shuffle_dyn(u8x8::splat(8)), u8x8::ZEROES);
+    assert_eq!(v.shuffle_dyn(u8x8::splat(0x80)), u8x8::ZEROES);
+    assert_eq!(v.shuffle_dyn(u8x8::splat(5)), u8x8::splat(6));
+}
+
+#[test]
+pub fn lookup_nibble() {
+    let table: [u8; 16] = core::array::from_fn(|i| (i * 16 + 15 - i) as u8);
+    let v = u8x8::from_array([0x00, 0x01, 0x1e, 0x2f, 0x37, 0x48, 0xa5, 0xff]);
+    assert_eq!(
+        v.lookup_nibble(table).to_array(),
+        [0x0f, 0x1e, 0xe1, 0xf0, 0x78, 0x87, 0x5a, 0xf0],
+    );
+    assert_eq!(
+        v.lookup_high_nibble(table).to_array(),
+        [0x0f, 0x0f, 0x1e, 0x2d, 0x3c, 0x4b, 0xa5, 0xf0],
+    );
+}
+
+#[test]
+pub fn extract_deposit_bits() {
+    let v = u8x8::from_array([0xff, 0xff, 0xff, 0xff, 0b1010_0101, 0b1010_0101, 0, 0x81]);
+    let mask = u8x8::from_array([0, 0xff, 0x80, 0b1010_1010, 0xf0, 0x0f, 0xff, 0x81]);
+    let extracted = v.extract_bits(mask);
+    assert_eq!(
+        extracted.to_array(),
+        [0, 0xff, 0b1, 0b1111, 0b1010, 0b0101, 0, 0b11],
+    );
+    // Depositing the extracted bits recovers the bits selected by the mask.
+    assert_eq!(extracted.deposit_bits(mask), v.bitand(mask));
+    assert_eq!(u8x8::splat(0xff).deposit_bits(mask), mask,);
+}
+
+#[test]
+pub fn prefix_sum() {
+    let v = u8x8::from_array([100, 100, 100, 0, 1, 0, 200, 1]);
+    assert_eq!(
+        v.wrapping_prefix_sum().to_array(),
+        [100, 200, 44, 44, 45, 45, 245, 246],
+    );
+    assert_eq!(
+        v.saturating_prefix_sum().to_array(),
+        [100, 200, 255, 255, 255, 255, 255, 255],
+    );
+    assert_eq!(
+        u8x8::splat(1).saturating_prefix_sum().to_array(),
+        [1, 2, 3, 4, 5, 6, 7, 8]
+    );
+}
+
+#[test]
+pub fn prefix_max() {
+    let v = u8x8::from_array([0, 5, 2, 255, 0, 7, 9, 1]);
+    assert_eq!(
+        v.prefix_max().to_array(),
+        [0, 5, 5, 255, 255, 255, 255, 255],
+    );
+    let sorted = u8x8::from_array([1, 1, 2, 3, 5, 8, 13, 21]);
+    assert_eq!(sorted.prefix_max(), sorted);
+}
+
+#[test]
+pub fn bits() {
+    let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(v.to_bits(), u64::from_ne_bytes([1, 2, 3, 4, 5, 6, 7, 8]));
+    assert_eq!(u8x8::from_bits(v.to_bits()), v);
+    assert_eq!(v.to_bits().to_le(), 0x0807060504030201);
+}
+
+#[test]
+pub fn conversions() {
+    let v: u8x8 = [1, 2, 3, 4, 5, 6, 7, 8].into();
+    assert_eq!(v, u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]));
+    assert_eq!(<[u8; 8]>::from(v), [1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(u64::from(v), v.to_bits());
+    assert_eq!(u8x8::from(v.to_bits()), v);
+
+    let bytes = [0_u8, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+    assert_eq!(
+        u8x8::try_from(&bytes[1..9]).unwrap(),
+        u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]),
+    );
+    assert!(u8x8::try_from(&bytes[..7]).is_err());
+    assert!(u8x8::try_from(&bytes[..]).is_err());
+}
+
+#[test]
+pub fn as_array() {
+    let mut v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(v.as_array(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+    v.as_array_mut()[0] = 0xff;
+    v.as_array_mut()[7] = 0xfe;
+    assert_eq!(v.to_array(), [0xff, 2, 3, 4, 5, 6, 7, 0xfe]);
+    assert_eq!(v.get(7), 0xfe);
+}
+
+#[test]
+pub fn index() {
+    let mut v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(v[0], 1);
+    assert_eq!(v[7], 8);
+    v[3] = 0xff;
+    v[4] += 10;
+    assert_eq!(v.to_array(), [1, 2, 3, 0xff, 15, 6, 7, 8]);
+    assert_eq!(v[3], v.get(3));
+}
+
+#[test]
+#[should_panic]
+pub fn index_out_of_range() {
+    let v = u8x8::ZEROES;
+    let i = core::hint::black_box(8);
+    let _ = v[i];
+}
+
+#[test]
+pub fn default() {
+    assert_eq!(u8x8::default(), u8x8::ZEROES);
+}
+
+#[test]
+pub fn hash() {
+    use std::collections::HashSet;
+    let set: HashSet<u8x8> = [
+        u8x8::ZEROES,
+        u8x8::splat(1),
+        u8x8::from_array([1, 1, 1, 1, 1, 1, 1, 1]),
+        u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(&u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8])));
+    assert!(!set.contains(&u8x8::splat(2)));
+}
+
+#[test]
+pub fn ord() {
+    let arrays = [
+        [0, 0, 0, 0, 0, 0, 0, 1],
+        [1, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 1, 0],
+        [0, 255, 255, 255, 255, 255, 255, 255],
+        [0, 0, 0, 0, 0, 0, 0, 0],
+        [1, 0, 0, 0, 0, 0, 0, 1],
+    ];
+    for a in arrays {
+        for b in arrays {
+            let got = u8x8::from_array(a).cmp(&u8x8::from_array(b));
+            assert_eq!(got, a.cmp(&b), "{a:?} {b:?}");
+        }
+    }
+    let mut vectors = arrays.map(u8x8::from_array);
+    let mut sorted = arrays;
+    vectors.sort();
+    sorted.sort();
+    assert_eq!(vectors.map(u8x8::to_array), sorted);
+}
+
+#[test]
+pub fn format() {
+    let v = u8x8::from_array([1, 255, 0, 0x2a, 0, 0, 0, 0x80]);
+    assert_eq!(format!("{v}"), "u8x8[1 255 0 42 0 0 0 128]");
+    assert_eq!(format!("{v:3}"), "u8x8[  1 255   0  42   0   0   0 128]");
+    assert_eq!(format!("{v:x}"), "u8x8[01 ff 00 2a 00 00 00 80]");
+    assert_eq!(format!("{v:X}"), "u8x8[01 FF 00 2A 00 00 00 80]");
+    assert_eq!(
+        format!("{v:b}"),
+        "u8x8[00000001 11111111 00000000 00101010 00000000 00000000 00000000 10000000]"
+    );
+    assert_eq!(
+        format!("{v:#x}"),
+        "u8x8[0x01 0xff 0x00 0x2a 0x00 0x00 0x00 0x80]"
+    );
+    assert_eq!(format!("{v:3x}"), "u8x8[  1  ff   0  2a   0   0   0  80]");
+    assert_eq!(format!("{v:<2X}"), "u8x8[1  FF 0  2A 0  0  0  80]");
+    assert_eq!(
+        format!("{v:#06x}"),
+        "u8x8[0x0001 0x00ff 0x0000 0x002a 0x0000 0x0000 0x0000 0x0080]"
+    );
+    assert_eq!(
+        format!("{v:#b}"),
+        "u8x8[0b00000001 0b11111111 0b00000000 0b00101010 0b00000000 0b00000000 0b00000000 0b10000000]"
+    );
+    assert_eq!(
+        format!("{v:1b}"),
+        "u8x8[1 11111111 0 101010 0 0 0 10000000]"
+    );
+}
+
+#[test]
+pub fn from_str() {
+    let want = u8x8::from_array([1, 2, 255, 0, 0x2a, 0, 0, 0x80]);
+    for s in [
+        "01:02:ff:00:2a:00:00:80",
+        "1:2:FF:0:2A:0:0:80",
+        "  01:02:ff:00:2a:00:00:80\n",
+        "[1,2,255,0,42,0,0,128]",
+        "[ 1, 2, 255, 0, 42, 0, 0, 128 ]",
+        "[1 2 255 0 42 0 0 128]",
+        "u8x8[1 2 255 0 42 0 0 128]",
+    ] {
+        assert_eq!(s.parse::<u8x8>(), Ok(want), "{s:?}");
+    }
+    assert_eq!(want.to_string().parse::<u8x8>(), Ok(want));
+
+    for (s, err) in [
+        ("", ParseU8x8Error::InvalidElement),
+        ("01:02:ff:00:2a:00:00", ParseU8x8Error::WrongLength),
+        ("01:02:ff:00:2a:00:00:80:00", ParseU8x8Error::WrongLength),
+        ("01:02:ff:00:2a:00:00:100", ParseU8x8Error::InvalidElement),
+        ("01:02:ff:00:2a:00::80", ParseU8x8Error::InvalidElement),
+        ("+1:02:ff:00:2a:00:00:80", ParseU8x8Error::InvalidElement),
+        ("[1,2,256,0,42,0,0,128]", ParseU8x8Error::InvalidElement),
+        ("[1,2,255,0,42,0,0,128,]", ParseU8x8Error::WrongLength),
+        ("[1,2,255,0,42,0,0]", ParseU8x8Error::WrongLength),
+        ("[]", ParseU8x8Error::WrongLength),
+        ("[1,2,ff,0,42,0,0,128]", ParseU8x8Error::InvalidElement),
+        ("[1,2,255,0,42,0,0,128", ParseU8x8Error::InvalidElement),
+    ] {
+        assert_eq!(s.parse::<u8x8>(), Err(err), "{s:?}");
+    }
+}
+
+#[test]
+pub fn sum_product() {
+    let vs = [
+        u8x8::from_array([1, 2, 3, 4, 200, 0, 16, 255]),
+        u8x8::from_array([1, 2, 3, 4, 100, 1, 16, 255]),
+        u8x8::from_array([1, 2, 3, 4, 1, 2, 16, 255]),
+    ];
+    let sum: u8x8 = vs.iter().sum();
+    assert_eq!(sum.to_array(), [3, 6, 9, 12, 45, 3, 48, 253]);
+    assert_eq!(vs.into_iter().sum::<u8x8>(), sum);
+    let product: u8x8 = vs.iter().product();
+    assert_eq!(product.to_array(), [1, 8, 27, 64, 32, 0, 0, 255]);
+    assert_eq!(vs.into_iter().product::<u8x8>(), product);
+
+    assert_eq!(core::iter::empty::<u8x8>().sum::<u8x8>(), u8x8::ZEROES);
+    assert_eq!(
+        core::iter::empty::<u8x8>().product::<u8x8>(),
+        u8x8::splat(1)
+    );
+}
+
+#[test]
+pub fn from_iter() {
+    assert_eq!(core::iter::empty().collect::<u8x8>(), u8x8::ZEROES);
+    assert_eq!(
+        (1..=3).collect::<u8x8>().to_array(),
+        [1, 2, 3, 0, 0, 0, 0, 0]
+    );
+    assert_eq!(
+        (1..=8).collect::<u8x8>().to_array(),
+        [1, 2, 3, 4, 5, 6, 7, 8]
+    );
+    let mut iter = 1..=10;
+    assert_eq!(
+        iter.by_ref().collect::<u8x8>().to_array(),
+        [1, 2, 3, 4, 5, 6, 7, 8]
+    );
+    assert_eq!(iter.next(), Some(9));
+}
+
+#[test]
+pub fn into_iter_ref() {
+    fn collect<'a, T>(v: &'a T) -> [u8; 8]
+    where
+        &'a T: IntoIterator<Item = u8>,
+    {
+        let mut ret = [0; 8];
+        for (dst, b) in ret.iter_mut().zip(v) {
+            *dst = b;
+        }
+        ret
+    }
+    let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(collect(&v), v.to_array());
+}
+
+#[test]
+pub fn map() {
+    let a = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 255]);
+    let b = u8x8::from_array([8, 7, 6, 5, 4, 3, 2, 1]);
+    assert_eq!(a.map(|x| x.wrapping_add(1)), a + 1);
+    assert_eq!(a.zip_map(b, u8::wrapping_sub), a - b);
+
+    let mut seen = [0; 8];
+    let mut n = 0;
+    a.map(|x| {
+        seen[n] = x;
+        n += 1;
+        x
+    });
+    assert_eq!(seen, a.to_array());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+pub fn serde() {
+    let v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 255]);
+    let json = serde_json::to_string(&v).unwrap();
+    assert_eq!(json, "[1,2,3,4,5,6,7,255]");
+    assert_eq!(serde_json::from_str::<u8x8>(&json).unwrap(), v);
+    assert!(serde_json::from_str::<u8x8>("[1,2,3,4,5,6,7]").is_err());
+    assert!(serde_json::from_str::<u8x8>("[1,2,3,4,5,6,7,256]").is_err());
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+pub fn bytemuck() {
+    let vs = [
+        u8x8::from_array(*b"abcdefgh"),
+        u8x8::from_array(*b"ijklmnop"),
+    ];
+    let bytes: &[u8] = bytemuck::cast_slice(&vs);
+    assert_eq!(bytes, b"abcdefghijklmnop");
+
+    let buf = [0_u64; 2];
+    let vs: &[u8x8] = bytemuck::cast_slice(&buf);
+    assert_eq!(vs, [u8x8::ZEROES; 2]);
+    assert_eq!(<u8x8 as bytemuck::Zeroable>::zeroed(), u8x8::ZEROES);
+}
+
+#[cfg(feature = "zerocopy")]
+#[test]
+pub fn zerocopy() {
+    use zerocopy::{FromBytes, IntoBytes};
+
+    let v = u8x8::from_array(*b"abcdefgh");
+    assert_eq!(v.as_bytes(), b"abcdefgh");
+    assert_eq!(u8x8::read_from_bytes(b"abcdefgh"), Ok(v));
+    assert!(u8x8::read_from_bytes(b"abc").is_err());
+
+    let (v, rest) = u8x8::read_from_prefix(b"ijklmnopqr").unwrap();
+    assert_eq!(v.to_array(), *b"ijklmnop");
+    assert_eq!(rest, b"qr");
+}
+
+#[cfg(feature = "rand")]
+#[test]
+pub fn rand() {
+    use rand::{Rng, RngExt, SeedableRng, rngs::StdRng};
+
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut want = StdRng::seed_from_u64(1);
+    let v: u8x8 = rng.random();
+    assert_eq!(v.to_array(), want.next_u64().to_le_bytes());
+
+    let mut vs = [u8x8::ZEROES; 3];
+    rng.fill(&mut vs[..]);
+    let mut bytes = [0_u8; 24];
+    want.fill_bytes(&mut bytes);
+    for (v, want) in vs.iter().zip(bytes.chunks_exact(8)) {
+        assert_eq!(v.as_array(), want);
+    }
+}
+
+#[test]
+pub fn for_each_lane_mut() {
+    let mut v = u8x8::from_array([1, 2, 3, 4, 5, 6, 7, 255]);
+    let mut seen = [0; 8];
+    v.for_each_lane_mut(|i, x| {
+        seen[i] = *x;
+        *x = x.wrapping_add(i as u8);
+    });
+    assert_eq!(seen, [1, 2, 3, 4, 5, 6, 7, 255]);
+    assert_eq!(v.to_array(), [1, 3, 5, 7, 9, 11, 13, 6]);
+}